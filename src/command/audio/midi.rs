@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use poise::serenity_prelude::{Attachment, CreateAttachment};
+use rustysynth::{MidiFile, MidiFileSequencer, SoundFont, Synthesizer, SynthesizerSettings};
+
+use crate::{
+    Context, Error,
+    error::BotError,
+    util::command::{check_cooldown, create_error_response},
+};
+
+const SAMPLE_RATE: i32 = 44100;
+
+#[poise::command(
+    slash_command,
+    description_localized(
+        "en-US",
+        "Render an uploaded MIDI file to audio using a bundled SoundFont"
+    )
+)]
+pub async fn midi(
+    ctx: Context<'_>,
+    #[description = "A .mid/.midi file to synthesize"] file: Attachment,
+) -> Result<(), Error> {
+    check_cooldown(&ctx, "midi", ctx.data().config.cooldowns.hash_cooldown).await?;
+
+    let lower_filename = file.filename.to_lowercase();
+    if !lower_filename.ends_with(".mid") && !lower_filename.ends_with(".midi") {
+        let embed = create_error_response("MIDI Error", "Please upload a `.mid` or `.midi` file.");
+        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        return Ok(());
+    }
+
+    let max_size = ctx.data().config.audio.max_midi_size as u64;
+    if file.size > max_size {
+        let embed = create_error_response(
+            "MIDI Error",
+            &format!(
+                "File is {} bytes, which exceeds the {} byte limit.",
+                file.size, max_size
+            ),
+        );
+        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        return Ok(());
+    }
+
+    ctx.defer().await?;
+
+    let midi_bytes = file.download().await?;
+
+    let midi_file = match MidiFile::new(&mut std::io::Cursor::new(midi_bytes)) {
+        Ok(midi_file) => Arc::new(midi_file),
+        Err(e) => {
+            let embed = create_error_response(
+                "MIDI Error",
+                &format!("Failed to parse MIDI file: {}", e),
+            );
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            return Ok(());
+        }
+    };
+
+    let max_duration = ctx.data().config.audio.max_duration_seconds;
+    let duration_seconds = midi_file.get_length() as u64;
+    if duration_seconds > max_duration {
+        let embed = create_error_response(
+            "MIDI Error",
+            &format!(
+                "MIDI file is {}s long, which exceeds the {}s limit.",
+                duration_seconds, max_duration
+            ),
+        );
+        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        return Ok(());
+    }
+
+    let soundfont_path = ctx.data().config.audio.soundfont_path.clone();
+    let wav_bytes = tokio::task::spawn_blocking(move || synthesize(&soundfont_path, &midi_file))
+        .await
+        .map_err(|e| BotError::InvalidFormat(format!("Synthesis task panicked: {}", e)))??;
+
+    let attachment = CreateAttachment::bytes(wav_bytes, format!("{}.wav", strip_extension(&file.filename)));
+    ctx.send(poise::CreateReply::default().attachment(attachment)).await?;
+    Ok(())
+}
+
+fn strip_extension(filename: &str) -> String {
+    filename
+        .rsplit_once('.')
+        .map(|(stem, _)| stem.to_string())
+        .unwrap_or_else(|| filename.to_string())
+}
+
+fn synthesize(soundfont_path: &str, midi_file: &Arc<MidiFile>) -> Result<Vec<u8>, BotError> {
+    let mut sf_file = std::fs::File::open(soundfont_path).map_err(|e| {
+        BotError::Config(format!("Failed to open SoundFont at {}: {}", soundfont_path, e))
+    })?;
+    let sound_font = Arc::new(
+        SoundFont::new(&mut sf_file)
+            .map_err(|e| BotError::Config(format!("Failed to load SoundFont: {}", e)))?,
+    );
+
+    let settings = SynthesizerSettings::new(SAMPLE_RATE);
+    let synthesizer = Synthesizer::new(&sound_font, &settings)
+        .map_err(|e| BotError::InvalidFormat(format!("Failed to init synthesizer: {}", e)))?;
+
+    let mut sequencer = MidiFileSequencer::new(synthesizer);
+    sequencer.play(midi_file, false);
+
+    let sample_count = (midi_file.get_length() * SAMPLE_RATE as f64).ceil() as usize;
+    let mut left = vec![0f32; sample_count];
+    let mut right = vec![0f32; sample_count];
+    sequencer.render(&mut left, &mut right);
+
+    encode_wav(&left, &right, SAMPLE_RATE as u32)
+}
+
+fn encode_wav(left: &[f32], right: &[f32], sample_rate: u32) -> Result<Vec<u8>, BotError> {
+    let spec = hound::WavSpec {
+        channels: 2,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+
+    let mut cursor = std::io::Cursor::new(Vec::new());
+    {
+        let mut writer = hound::WavWriter::new(&mut cursor, spec)
+            .map_err(|e| BotError::InvalidFormat(format!("Failed to create WAV writer: {}", e)))?;
+
+        for (l, r) in left.iter().zip(right.iter()) {
+            writer
+                .write_sample((l.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+                .map_err(|e| BotError::InvalidFormat(e.to_string()))?;
+            writer
+                .write_sample((r.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+                .map_err(|e| BotError::InvalidFormat(e.to_string()))?;
+        }
+
+        writer
+            .finalize()
+            .map_err(|e| BotError::InvalidFormat(e.to_string()))?;
+    }
+
+    Ok(cursor.into_inner())
+}
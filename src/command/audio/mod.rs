@@ -0,0 +1,3 @@
+pub mod midi;
+
+pub use midi::midi;
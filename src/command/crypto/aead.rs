@@ -0,0 +1,113 @@
+use crate::{
+    Context, Error,
+    util::{
+        command::{
+            check_cooldown, create_error_response, create_success_response, validate_input_size,
+        },
+        keyed_crypto::AeadAlgorithm,
+    },
+};
+
+#[derive(poise::ChoiceParameter)]
+pub enum AeadChoice {
+    #[name = "AES-256-GCM"]
+    Aes256Gcm,
+    #[name = "ChaCha20-Poly1305"]
+    ChaCha20Poly1305,
+}
+
+impl AeadChoice {
+    fn to_algorithm(&self) -> AeadAlgorithm {
+        match self {
+            AeadChoice::Aes256Gcm => AeadAlgorithm::Aes256Gcm,
+            AeadChoice::ChaCha20Poly1305 => AeadAlgorithm::ChaCha20Poly1305,
+        }
+    }
+}
+
+#[derive(poise::ChoiceParameter)]
+pub enum AeadOperation {
+    #[name = "Encrypt"]
+    Encrypt,
+    #[name = "Decrypt"]
+    Decrypt,
+}
+
+#[poise::command(
+    slash_command,
+    description_localized(
+        "en-US",
+        "Encrypt or decrypt data with AES-256-GCM or ChaCha20-Poly1305"
+    )
+)]
+pub async fn aead(
+    ctx: Context<'_>,
+    #[description = "Cipher to use"] algorithm: AeadChoice,
+    #[description = "Encrypt or decrypt"] operation: AeadOperation,
+    #[description = "32-byte key, hex-encoded"] key: String,
+    #[description = "Encrypt: plaintext. Decrypt: hex-encoded nonce||ciphertext||tag"] data: String,
+) -> Result<(), Error> {
+    check_cooldown(&ctx, "aead", ctx.data().config.cooldowns.hash_cooldown).await?;
+
+    let algo = algorithm.to_algorithm();
+
+    let key_bytes = match hex::decode(&key) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            let embed = create_error_response("AEAD Error", &format!("Invalid hex in `key`: {}", e));
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            return Ok(());
+        }
+    };
+
+    let (title, content) = match operation {
+        AeadOperation::Encrypt => {
+            if let Err(e) = validate_input_size(&data, &ctx.data().config) {
+                let embed = create_error_response("AEAD Error", &e.to_string());
+                ctx.send(poise::CreateReply::default().embed(embed)).await?;
+                return Ok(());
+            }
+
+            match algo.encrypt(&key_bytes, data.as_bytes()) {
+                Ok(sealed) => (format!("{} Encrypted", algo.name()), hex::encode(sealed)),
+                Err(e) => {
+                    let embed = create_error_response("AEAD Error", &e.to_string());
+                    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+                    return Ok(());
+                }
+            }
+        }
+        AeadOperation::Decrypt => {
+            let sealed = match hex::decode(&data) {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    let embed =
+                        create_error_response("AEAD Error", &format!("Invalid hex in `data`: {}", e));
+                    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+                    return Ok(());
+                }
+            };
+
+            match algo.decrypt(&key_bytes, &sealed) {
+                Ok(plaintext) => match String::from_utf8(plaintext) {
+                    Ok(text) => (format!("{} Decrypted", algo.name()), text),
+                    Err(_) => {
+                        let embed =
+                            create_error_response("AEAD Error", "Decrypted data is not valid UTF-8");
+                        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+                        return Ok(());
+                    }
+                },
+                Err(e) => {
+                    let embed = create_error_response("AEAD Error", &e.to_string());
+                    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+                    return Ok(());
+                }
+            }
+        }
+    };
+
+    let embed = create_success_response(&title, &content, true, &ctx.data().config);
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
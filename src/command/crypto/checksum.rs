@@ -1,10 +1,15 @@
+use std::time::Instant;
+
+use futures::StreamExt;
+use poise::serenity_prelude::Attachment;
+
 use crate::{
     Context, Error,
     util::{
         command::{
             check_cooldown, create_error_response, create_success_response, validate_input_size,
         },
-        crypto::{calculate_adler32, calculate_crc32},
+        crypto::{ChecksumKind, ChecksumStream},
     },
 };
 
@@ -14,52 +19,147 @@ pub enum ChecksumAlgorithm {
     CRC32,
     #[name = "Adler32"]
     Adler32,
+    #[name = "CRC32C"]
+    CRC32C,
+    #[name = "xxHash64"]
+    XxHash64,
+    #[name = "SHA-256"]
+    SHA256,
 }
 
 impl ChecksumAlgorithm {
-    fn name(&self) -> &'static str {
+    fn kind(&self) -> ChecksumKind {
         match self {
-            ChecksumAlgorithm::CRC32 => "CRC32",
-            ChecksumAlgorithm::Adler32 => "Adler32",
+            ChecksumAlgorithm::CRC32 => ChecksumKind::Crc32,
+            ChecksumAlgorithm::Adler32 => ChecksumKind::Adler32,
+            ChecksumAlgorithm::CRC32C => ChecksumKind::Crc32c,
+            ChecksumAlgorithm::XxHash64 => ChecksumKind::XxHash64,
+            ChecksumAlgorithm::SHA256 => ChecksumKind::Sha256,
         }
     }
 }
 
+const ALL_KINDS: [ChecksumKind; 5] = [
+    ChecksumKind::Crc32,
+    ChecksumKind::Adler32,
+    ChecksumKind::Crc32c,
+    ChecksumKind::XxHash64,
+    ChecksumKind::Sha256,
+];
+
 #[poise::command(
     slash_command,
-    description_localized("en-US", "Calculate checksums of data for integrity verification")
+    description_localized(
+        "en-US",
+        "Calculate checksums of text or an uploaded file for integrity verification"
+    )
 )]
 pub async fn checksum(
     ctx: Context<'_>,
     #[description = "Checksum algorithm to use"] algorithm: ChecksumAlgorithm,
-    #[description = "The data to calculate checksum for"] data: String,
+    #[description = "The data to calculate a checksum for"] data: Option<String>,
+    #[description = "A file to checksum instead of raw text"] file: Option<Attachment>,
+    #[description = "Compute every supported algorithm in the same pass"] all: Option<bool>,
 ) -> Result<(), Error> {
     check_cooldown(&ctx, "checksum", ctx.data().config.cooldowns.hash_cooldown).await?;
 
-    if let Err(e) = validate_input_size(&data, &ctx.data().config) {
-        let embed = create_error_response("Checksum Error", &e.to_string());
-        ctx.send(poise::CreateReply::default().embed(embed)).await?;
-        return Ok(());
-    }
+    let kinds: Vec<ChecksumKind> = if all.unwrap_or(false) {
+        ALL_KINDS.to_vec()
+    } else {
+        vec![algorithm.kind()]
+    };
 
-    let (title, result) = match algorithm {
-        ChecksumAlgorithm::CRC32 => {
-            let checksum = calculate_crc32(data.as_bytes());
-            (
-                format!("{} Checksum", algorithm.name()),
-                format!("{:08x}", checksum),
-            )
+    let (title, content) = match (data, file) {
+        (Some(_), Some(_)) => {
+            let embed =
+                create_error_response("Checksum Error", "Provide either `data` or `file`, not both.");
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            return Ok(());
         }
-        ChecksumAlgorithm::Adler32 => {
-            let checksum = calculate_adler32(data.as_bytes());
-            (
-                format!("{} Checksum", algorithm.name()),
-                format!("{:08x}", checksum),
-            )
+        (None, None) => {
+            let embed = create_error_response("Checksum Error", "Provide either `data` or `file`.");
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            return Ok(());
+        }
+        (Some(data), None) => match validate_input_size(&data, &ctx.data().config) {
+            Ok(()) => {
+                let mut stream = ChecksumStream::new(&kinds);
+                stream.update(data.as_bytes());
+                (checksum_title(&kinds, None), format_results(stream.finalize()))
+            }
+            Err(e) => {
+                let embed = create_error_response("Checksum Error", &e.to_string());
+                ctx.send(poise::CreateReply::default().embed(embed)).await?;
+                return Ok(());
+            }
+        },
+        (None, Some(attachment)) => {
+            let max_size = ctx.data().config.limits.max_input_size as u64;
+            if attachment.size > max_size {
+                let embed = create_error_response(
+                    "Checksum Error",
+                    &format!(
+                        "File is {} bytes, which exceeds the {} byte limit.",
+                        attachment.size, max_size
+                    ),
+                );
+                ctx.send(poise::CreateReply::default().embed(embed)).await?;
+                return Ok(());
+            }
+
+            ctx.defer().await?;
+
+            let started = Instant::now();
+            let response = reqwest::get(&attachment.url).await?;
+            let mut byte_stream = response.bytes_stream();
+            let mut stream = ChecksumStream::new(&kinds);
+            let mut total_bytes: u64 = 0;
+
+            while let Some(chunk) = byte_stream.next().await {
+                let chunk = chunk?;
+                stream.update(&chunk);
+                total_bytes += chunk.len() as u64;
+            }
+
+            let elapsed = started.elapsed();
+            let throughput_mb_s = if elapsed.as_secs_f64() > 0.0 {
+                (total_bytes as f64 / 1_048_576.0) / elapsed.as_secs_f64()
+            } else {
+                0.0
+            };
+
+            let mut content = format_results(stream.finalize());
+            content.push_str(&format!(
+                "\n\n**Bytes:** {}\n**Elapsed:** {:.2?}\n**Throughput:** {:.2} MB/s",
+                total_bytes, elapsed, throughput_mb_s
+            ));
+
+            (checksum_title(&kinds, Some(&attachment.filename)), content)
         }
     };
 
-    let embed = create_success_response(&title, &result, true, &ctx.data().config);
+    let embed = create_success_response(&title, &content, true, &ctx.data().config);
     ctx.send(poise::CreateReply::default().embed(embed)).await?;
     Ok(())
 }
+
+fn checksum_title(kinds: &[ChecksumKind], filename: Option<&str>) -> String {
+    let algo_label = if kinds.len() == 1 {
+        kinds[0].name().to_string()
+    } else {
+        "Multi-Algorithm".to_string()
+    };
+
+    match filename {
+        Some(name) => format!("{} Checksum: {}", algo_label, name),
+        None => format!("{} Checksum", algo_label),
+    }
+}
+
+fn format_results(results: Vec<(ChecksumKind, String)>) -> String {
+    results
+        .into_iter()
+        .map(|(kind, value)| format!("**{}:** `{}`", kind.name(), value))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
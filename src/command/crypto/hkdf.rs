@@ -0,0 +1,111 @@
+use crate::{
+    Context, Error,
+    util::{
+        command::{check_cooldown, create_error_response, create_success_response},
+        keyed_crypto::{HmacAlgorithm, hkdf_expand, hkdf_extract},
+    },
+};
+
+#[derive(poise::ChoiceParameter)]
+pub enum HkdfHashChoice {
+    #[name = "SHA256"]
+    Sha256,
+    #[name = "SHA384"]
+    Sha384,
+    #[name = "SHA512"]
+    Sha512,
+}
+
+impl HkdfHashChoice {
+    fn to_algorithm(&self) -> HmacAlgorithm {
+        match self {
+            HkdfHashChoice::Sha256 => HmacAlgorithm::Sha256,
+            HkdfHashChoice::Sha384 => HmacAlgorithm::Sha384,
+            HkdfHashChoice::Sha512 => HmacAlgorithm::Sha512,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            HkdfHashChoice::Sha256 => "SHA256",
+            HkdfHashChoice::Sha384 => "SHA384",
+            HkdfHashChoice::Sha512 => "SHA512",
+        }
+    }
+}
+
+#[poise::command(
+    slash_command,
+    description_localized(
+        "en-US",
+        "Derive key material from input keying material using HKDF (RFC 5869)"
+    )
+)]
+pub async fn hkdf(
+    ctx: Context<'_>,
+    #[description = "Hash function backing the HMAC"] hash: HkdfHashChoice,
+    #[description = "Input keying material, hex-encoded"] ikm: String,
+    #[description = "Salt, hex-encoded (defaults to empty)"] salt: Option<String>,
+    #[description = "Context/application info, hex-encoded (defaults to empty)"] info: Option<
+        String,
+    >,
+    #[description = "Number of output bytes to derive (1-2040)"]
+    #[min = 1]
+    #[max = 2040]
+    length: u16,
+) -> Result<(), Error> {
+    check_cooldown(&ctx, "hkdf", ctx.data().config.cooldowns.hash_cooldown).await?;
+
+    let algo = hash.to_algorithm();
+
+    let ikm_bytes = match hex::decode(&ikm) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            let embed = create_error_response("HKDF Error", &format!("Invalid hex in `ikm`: {}", e));
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            return Ok(());
+        }
+    };
+
+    let salt_bytes = match salt.as_deref().map(hex::decode).transpose() {
+        Ok(bytes) => bytes.unwrap_or_default(),
+        Err(e) => {
+            let embed =
+                create_error_response("HKDF Error", &format!("Invalid hex in `salt`: {}", e));
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            return Ok(());
+        }
+    };
+
+    let info_bytes = match info.as_deref().map(hex::decode).transpose() {
+        Ok(bytes) => bytes.unwrap_or_default(),
+        Err(e) => {
+            let embed =
+                create_error_response("HKDF Error", &format!("Invalid hex in `info`: {}", e));
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            return Ok(());
+        }
+    };
+
+    let prk = hkdf_extract(algo, &salt_bytes, &ikm_bytes);
+    let okm = match hkdf_expand(algo, &prk, &info_bytes, length as usize) {
+        Ok(okm) => okm,
+        Err(e) => {
+            let embed = create_error_response("HKDF Error", &e.to_string());
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            return Ok(());
+        }
+    };
+
+    let content = format!(
+        "**PRK:** `{}`\n**OKM ({} bytes):** `{}`",
+        hex::encode(&prk),
+        okm.len(),
+        hex::encode(&okm)
+    );
+
+    let title = format!("HKDF-{}", hash.name());
+    let embed = create_success_response(&title, &content, false, &ctx.data().config);
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
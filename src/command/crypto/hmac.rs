@@ -0,0 +1,56 @@
+use crate::{
+    Context, Error,
+    util::{
+        command::{
+            check_cooldown, create_error_response, create_success_response, validate_input_size,
+        },
+        keyed_crypto::HmacAlgorithm,
+    },
+};
+
+#[derive(poise::ChoiceParameter)]
+pub enum HmacChoice {
+    #[name = "HMAC-SHA256"]
+    Sha256,
+    #[name = "HMAC-SHA384"]
+    Sha384,
+    #[name = "HMAC-SHA512"]
+    Sha512,
+}
+
+impl HmacChoice {
+    fn to_algorithm(&self) -> HmacAlgorithm {
+        match self {
+            HmacChoice::Sha256 => HmacAlgorithm::Sha256,
+            HmacChoice::Sha384 => HmacAlgorithm::Sha384,
+            HmacChoice::Sha512 => HmacAlgorithm::Sha512,
+        }
+    }
+}
+
+#[poise::command(
+    slash_command,
+    description_localized("en-US", "Compute an HMAC tag over a message with a secret key")
+)]
+pub async fn hmac(
+    ctx: Context<'_>,
+    #[description = "HMAC algorithm to use"] algorithm: HmacChoice,
+    #[description = "Secret key"] key: String,
+    #[description = "Message to authenticate"] message: String,
+) -> Result<(), Error> {
+    check_cooldown(&ctx, "hmac", ctx.data().config.cooldowns.hash_cooldown).await?;
+
+    if let Err(e) = validate_input_size(&message, &ctx.data().config) {
+        let embed = create_error_response("HMAC Error", &e.to_string());
+        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        return Ok(());
+    }
+
+    let algo = algorithm.to_algorithm();
+    let tag = algo.tag(key.as_bytes(), message.as_bytes());
+    let title = format!("{} Tag", algo.name());
+    let embed = create_success_response(&title, &hex::encode(tag), true, &ctx.data().config);
+
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
@@ -1,7 +1,13 @@
+pub mod aead;
 pub mod checksum;
 pub mod hash;
+pub mod hkdf;
+pub mod hmac;
 pub mod uuid;
 
+pub use aead::aead;
 pub use checksum::checksum;
 pub use hash::hash;
+pub use hkdf::hkdf;
+pub use hmac::hmac;
 pub use uuid::uuid;
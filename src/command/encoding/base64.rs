@@ -1,6 +1,11 @@
 use crate::{
     Context, Error,
-    util::command::{create_error_response, create_success_response, validate_input_size},
+    util::{
+        command::{
+            check_cooldown, create_error_response, create_success_response, validate_input_size,
+        },
+        transforms::{base64_decode, base64_encode},
+    },
 };
 
 #[derive(poise::ChoiceParameter)]
@@ -20,46 +25,27 @@ pub async fn base64(
     #[description = "Choose operation"] operation: Operation,
     #[description = "The data to encode or decode"] data: String,
 ) -> Result<(), Error> {
-    if let Err(e) = validate_input_size(&data) {
-        let embed = create_error_response("Base64 Error", &e);
+    check_cooldown(&ctx, "base64", ctx.data().config.cooldowns.per_user_cooldown).await?;
+
+    if let Err(e) = validate_input_size(&data, &ctx.data().config) {
+        let embed = create_error_response("Base64 Error", &e.to_string());
         ctx.send(poise::CreateReply::default().embed(embed)).await?;
         return Ok(());
     }
 
-    let (title, result, is_success) = match operation {
-        Operation::Encode => {
-            let encoded = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &data);
-            ("Base64 Encoded", encoded, true)
-        }
-        Operation::Decode => {
-            match base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &data) {
-                Ok(decoded) => match String::from_utf8(decoded) {
-                    Ok(utf8_string) => ("Base64 Decoded", utf8_string, true),
-                    Err(_) => {
-                        let embed = create_error_response(
-                            "Base64 Error",
-                            "Decoded data is not valid UTF-8",
-                        );
-                        ctx.send(poise::CreateReply::default().embed(embed)).await?;
-                        return Ok(());
-                    }
-                },
-                Err(e) => {
-                    let embed =
-                        create_error_response("Base64 Error", &format!("Invalid base64: {}", e));
-                    ctx.send(poise::CreateReply::default().embed(embed)).await?;
-                    return Ok(());
-                }
+    let (title, result) = match operation {
+        Operation::Encode => ("Base64 Encoded", base64_encode(&data)),
+        Operation::Decode => match base64_decode(&data) {
+            Ok(decoded) => ("Base64 Decoded", decoded),
+            Err(e) => {
+                let embed = create_error_response("Base64 Error", &e);
+                ctx.send(poise::CreateReply::default().embed(embed)).await?;
+                return Ok(());
             }
-        }
-    };
-
-    let embed = if is_success {
-        create_success_response(title, &result, true)
-    } else {
-        create_error_response(title, &result)
+        },
     };
 
+    let embed = create_success_response(&title, &result, true, &ctx.data().config);
     ctx.send(poise::CreateReply::default().embed(embed)).await?;
     Ok(())
 }
@@ -1,11 +1,13 @@
 pub mod base64;
 pub mod endian;
+pub mod recipe;
 pub mod rot;
 pub mod timestamp;
 pub mod url;
 
 pub use base64::base64;
 pub use endian::endian;
+pub use recipe::recipe;
 pub use rot::rot;
 pub use timestamp::timestamp;
 pub use url::url;
@@ -0,0 +1,107 @@
+use crate::{
+    Context, Error,
+    util::{
+        command::{
+            check_cooldown, create_error_response, create_success_response, validate_input_size,
+        },
+        dot::{DotBuilder, Kind},
+        transforms::TRANSFORMS,
+    },
+};
+
+#[poise::command(
+    slash_command,
+    description_localized(
+        "en-US",
+        "Chain encoding/cipher transforms together, e.g. `base64:decode | rot:13 | url:encode`"
+    )
+)]
+pub async fn recipe(
+    ctx: Context<'_>,
+    #[description = "Pipeline, e.g. `base64:decode | rot:13 | url:encode | endian`"] pipeline: String,
+    #[description = "The input to run through the pipeline"] input: String,
+    #[description = "Render the pipeline as Graphviz DOT instead of running it"] graph: Option<bool>,
+) -> Result<(), Error> {
+    check_cooldown(&ctx, "recipe", ctx.data().config.cooldowns.per_user_cooldown).await?;
+
+    if let Err(e) = validate_input_size(&input, &ctx.data().config) {
+        let embed = create_error_response("Recipe Error", &e.to_string());
+        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        return Ok(());
+    }
+
+    let steps: Vec<&str> = pipeline
+        .split('|')
+        .map(|step| step.trim())
+        .filter(|step| !step.is_empty())
+        .collect();
+
+    if steps.is_empty() {
+        let embed = create_error_response("Recipe Error", "Pipeline must contain at least one step.");
+        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        return Ok(());
+    }
+
+    if graph.unwrap_or(false) {
+        let dot = pipeline_dot(&steps);
+        let embed = create_success_response("Recipe Pipeline (graph)", &dot, true, &ctx.data().config);
+        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        return Ok(());
+    }
+
+    let mut value = input;
+
+    for (index, step) in steps.iter().enumerate() {
+        let (op, arg) = match step.split_once(':') {
+            Some((op, arg)) => (op.trim(), Some(arg.trim())),
+            None => (*step, None),
+        };
+
+        let Some(transform) = TRANSFORMS.get(op) else {
+            let embed = create_error_response(
+                "Recipe Error",
+                &format!("Step {} failed: unknown transform '{}'", index + 1, op),
+            );
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            return Ok(());
+        };
+
+        value = match transform(&value, arg) {
+            Ok(result) => result,
+            Err(e) => {
+                let embed = create_error_response(
+                    "Recipe Error",
+                    &format!("Step {} (`{}`) failed: {}", index + 1, step, e),
+                );
+                ctx.send(poise::CreateReply::default().embed(embed)).await?;
+                return Ok(());
+            }
+        };
+    }
+
+    let embed = create_success_response("Recipe Result", &value, true, &ctx.data().config);
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
+
+/// Renders the pipeline as a linear digraph: `input -> step 1 -> step 2 ->
+/// ... -> output`, with each node labeled by its transform step text.
+fn pipeline_dot(steps: &[&str]) -> String {
+    let mut dot = DotBuilder::new(Kind::Digraph, "recipe");
+
+    dot.node("input", "input");
+    for (index, step) in steps.iter().enumerate() {
+        dot.node(&format!("step{}", index), step);
+    }
+    dot.node("output", "output");
+
+    let mut previous = "input".to_string();
+    for index in 0..steps.len() {
+        let current = format!("step{}", index);
+        dot.edge(&previous, &current, "");
+        previous = current;
+    }
+    dot.edge(&previous, "output", "");
+
+    dot.build()
+}
@@ -1,20 +1,11 @@
 use crate::{
     Context, Error,
-    util::command::{check_cooldown, create_error_response, create_success_response, validate_input_size},
+    util::{
+        command::{check_cooldown, create_error_response, create_success_response, validate_input_size},
+        transforms::rot_string,
+    },
 };
 
-fn rot_char(c: char, n: u8) -> char {
-    match c {
-        'a'..='z' => ((c as u8 - b'a' + n) % 26 + b'a') as char,
-        'A'..='Z' => ((c as u8 - b'A' + n) % 26 + b'A') as char,
-        _ => c,
-    }
-}
-
-fn rot_string(s: &str, n: u8) -> String {
-    s.chars().map(|c| rot_char(c, n)).collect()
-}
-
 #[poise::command(
     slash_command,
     description_localized("en-US", "Apply ROT cipher to text with custom rotation value")
@@ -41,4 +32,4 @@ pub async fn rot(
 
     ctx.send(poise::CreateReply::default().embed(embed)).await?;
     Ok(())
-}
\ No newline at end of file
+}
@@ -5,6 +5,50 @@ use crate::{
     util::command::{check_cooldown, create_error_response, create_success_response},
 };
 
+#[derive(poise::ChoiceParameter)]
+pub enum DiscordTimestampStyle {
+    #[name = "Short Time"]
+    ShortTime,
+    #[name = "Long Time"]
+    LongTime,
+    #[name = "Short Date"]
+    ShortDate,
+    #[name = "Long Date"]
+    LongDate,
+    #[name = "Short Date/Time"]
+    ShortDateTime,
+    #[name = "Long Date/Time"]
+    LongDateTime,
+    #[name = "Relative"]
+    Relative,
+}
+
+impl DiscordTimestampStyle {
+    fn letter(&self) -> char {
+        match self {
+            DiscordTimestampStyle::ShortTime => 't',
+            DiscordTimestampStyle::LongTime => 'T',
+            DiscordTimestampStyle::ShortDate => 'd',
+            DiscordTimestampStyle::LongDate => 'D',
+            DiscordTimestampStyle::ShortDateTime => 'f',
+            DiscordTimestampStyle::LongDateTime => 'F',
+            DiscordTimestampStyle::Relative => 'R',
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            DiscordTimestampStyle::ShortTime => "Short Time",
+            DiscordTimestampStyle::LongTime => "Long Time",
+            DiscordTimestampStyle::ShortDate => "Short Date",
+            DiscordTimestampStyle::LongDate => "Long Date",
+            DiscordTimestampStyle::ShortDateTime => "Short Date/Time",
+            DiscordTimestampStyle::LongDateTime => "Long Date/Time",
+            DiscordTimestampStyle::Relative => "Relative",
+        }
+    }
+}
+
 #[poise::command(
     slash_command,
     description_localized("en-US", "Convert Unix timestamps to human-readable dates")
@@ -12,9 +56,11 @@ use crate::{
 pub async fn timestamp(
     ctx: Context<'_>,
     #[description = "Unix timestamp (leave empty to get current timestamp)"] timestamp: Option<i64>,
-    #[description = "Date string to convert to timestamp (format: YYYY-MM-DD HH:MM:SS)"]
+    #[description = "Date string (YYYY-MM-DD HH:MM:SS, 'in 3 days', '2h30m', '-1 week', 'next friday')"]
     date: Option<String>,
     #[description = "Show in local timezone instead of UTC"] local: Option<bool>,
+    #[description = "Also render as a Discord dynamic timestamp (per-viewer locale/timezone)"]
+    style: Option<DiscordTimestampStyle>,
 ) -> Result<(), Error> {
     check_cooldown(
         &ctx,
@@ -25,7 +71,7 @@ pub async fn timestamp(
 
     let _use_local = local.unwrap_or(false);
 
-    let (title, content) = if let Some(ts) = timestamp {
+    let (title, mut content, resolved_ts) = if let Some(ts) = timestamp {
         match Utc.timestamp_opt(ts, 0) {
             chrono::LocalResult::Single(dt) => {
                 let utc_str = dt.format("%Y-%m-%d %H:%M:%S UTC").to_string();
@@ -44,7 +90,7 @@ pub async fn timestamp(
                     dt.to_rfc2822()
                 );
 
-                ("Timestamp Conversion", content)
+                ("Timestamp Conversion", content, ts)
             }
             _ => {
                 let embed = create_error_response(
@@ -72,13 +118,14 @@ pub async fn timestamp(
                     dt.to_rfc2822()
                 );
 
-                ("Date Conversion", content)
+                ("Date Conversion", content, timestamp)
             }
             Err(e) => {
                 let embed = create_error_response(
                     "Invalid date format",
                     &format!(
-                        "Could not parse date: {}\n\nExpected format: YYYY-MM-DD HH:MM:SS",
+                        "Could not parse date: {}\n\nExpected YYYY-MM-DD HH:MM:SS, or a relative \
+                         expression like 'in 3 days', '2h30m', '-1 week', 'next friday'",
                         e
                     ),
                 );
@@ -103,15 +150,31 @@ pub async fn timestamp(
             now.to_rfc2822()
         );
 
-        ("Current Timestamp", content)
+        ("Current Timestamp", content, timestamp)
     };
 
+    if let Some(style) = style {
+        let token = format!("<t:{}:{}>", resolved_ts, style.letter());
+        content.push_str(&format!(
+            "\n\n**Discord Markdown ({}):**\n`{}`\n**Preview:** {}",
+            style.label(),
+            token,
+            token
+        ));
+    }
+
     let embed = create_success_response(title, &content, false, &ctx.data().config);
     ctx.send(poise::CreateReply::default().embed(embed)).await?;
     Ok(())
 }
 
-fn parse_date_string(date_str: &str) -> Result<DateTime<Utc>, String> {
+pub(crate) fn parse_date_string(date_str: &str) -> Result<DateTime<Utc>, String> {
+    let trimmed = date_str.trim();
+
+    if let Some(dt) = parse_relative_date_string(trimmed) {
+        return Ok(dt);
+    }
+
     let formats = [
         "%Y-%m-%d %H:%M:%S",
         "%Y-%m-%d %H:%M",
@@ -136,6 +199,117 @@ fn parse_date_string(date_str: &str) -> Result<DateTime<Utc>, String> {
     Err("Invalid date format".to_string())
 }
 
+/// Resolves relative expressions (`in 3 days`, `2h30m`, `-1 week`, `next
+/// friday`, and a handful of keywords) against `Utc::now()`.
+fn parse_relative_date_string(input: &str) -> Option<DateTime<Utc>> {
+    let lower = input.to_lowercase();
+
+    match lower.as_str() {
+        "now" | "today" => return Some(Utc::now()),
+        "tomorrow" => return Some(Utc::now() + chrono::Duration::days(1)),
+        "yesterday" => return Some(Utc::now() - chrono::Duration::days(1)),
+        _ => {}
+    }
+
+    if let Some(rest) = lower.strip_prefix("next ") {
+        if let Some(weekday) = parse_weekday_name(rest.trim()) {
+            return Some(next_weekday(Utc::now(), weekday));
+        }
+    }
+
+    if let Some(rest) = lower.strip_prefix("in ") {
+        return to_duration(rest).map(|d| Utc::now() + d);
+    }
+
+    if let Some(rest) = lower.strip_suffix(" ago") {
+        return to_duration(rest).map(|d| Utc::now() - d);
+    }
+
+    if let Some(rest) = lower.strip_prefix('-') {
+        return to_duration(rest).map(|d| Utc::now() - d);
+    }
+
+    to_duration(&lower).map(|d| Utc::now() + d)
+}
+
+/// Tokenizes a string for `<number><unit>` groups (unit in {s,m,h,d,w} or
+/// their long forms), summing them into a single `chrono::Duration`.
+/// Returns `None` if no group is found or the string contains anything that
+/// isn't a recognized group.
+fn to_duration(input: &str) -> Option<chrono::Duration> {
+    let mut chars = input.trim().chars().peekable();
+    let mut total = chrono::Duration::zero();
+    let mut matched_any = false;
+
+    loop {
+        while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+            chars.next();
+        }
+
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let mut number = String::new();
+        while matches!(chars.peek(), Some(c) if c.is_ascii_digit()) {
+            number.push(chars.next().unwrap());
+        }
+        if number.is_empty() {
+            return None;
+        }
+        let value: i64 = number.parse().ok()?;
+
+        while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+            chars.next();
+        }
+
+        let mut unit = String::new();
+        while matches!(chars.peek(), Some(c) if c.is_ascii_alphabetic()) {
+            unit.push(chars.next().unwrap());
+        }
+
+        let unit_duration = match unit.as_str() {
+            "s" | "sec" | "secs" | "second" | "seconds" => chrono::Duration::seconds(value),
+            "m" | "min" | "mins" | "minute" | "minutes" => chrono::Duration::minutes(value),
+            "h" | "hr" | "hrs" | "hour" | "hours" => chrono::Duration::hours(value),
+            "d" | "day" | "days" => chrono::Duration::days(value),
+            "w" | "wk" | "wks" | "week" | "weeks" => chrono::Duration::weeks(value),
+            _ => return None,
+        };
+
+        total += unit_duration;
+        matched_any = true;
+    }
+
+    matched_any.then_some(total)
+}
+
+fn parse_weekday_name(name: &str) -> Option<chrono::Weekday> {
+    match name {
+        "monday" | "mon" => Some(chrono::Weekday::Mon),
+        "tuesday" | "tue" => Some(chrono::Weekday::Tue),
+        "wednesday" | "wed" => Some(chrono::Weekday::Wed),
+        "thursday" | "thu" => Some(chrono::Weekday::Thu),
+        "friday" | "fri" => Some(chrono::Weekday::Fri),
+        "saturday" | "sat" => Some(chrono::Weekday::Sat),
+        "sunday" | "sun" => Some(chrono::Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn next_weekday(from: DateTime<Utc>, target: chrono::Weekday) -> DateTime<Utc> {
+    use chrono::Datelike;
+
+    let from_ordinal = from.weekday().num_days_from_monday() as i64;
+    let target_ordinal = target.num_days_from_monday() as i64;
+    let mut days_ahead = (target_ordinal - from_ordinal).rem_euclid(7);
+    if days_ahead == 0 {
+        days_ahead = 7;
+    }
+
+    from + chrono::Duration::days(days_ahead)
+}
+
 fn format_relative_time(timestamp: i64) -> String {
     let now = Utc::now().timestamp();
     let diff = now - timestamp;
@@ -1,7 +1,10 @@
 use crate::{
     Context, Error,
-    util::command::{
-        check_cooldown, create_error_response, create_success_response, validate_input_size,
+    util::{
+        command::{
+            check_cooldown, create_error_response, create_success_response, validate_input_size,
+        },
+        transforms::{url_decode, url_encode},
     },
 };
 
@@ -31,17 +34,11 @@ pub async fn url(
     }
 
     let (title, result) = match operation {
-        Operation::Encode => {
-            let encoded = urlencoding::encode(&data);
-            ("URL Encoded", encoded.to_string())
-        }
-        Operation::Decode => match urlencoding::decode(&data) {
-            Ok(decoded) => ("URL Decoded", decoded.to_string()),
+        Operation::Encode => ("URL Encoded", url_encode(&data)),
+        Operation::Decode => match url_decode(&data) {
+            Ok(decoded) => ("URL Decoded", decoded),
             Err(e) => {
-                let embed = create_error_response(
-                    "URL Encoding Error",
-                    &format!("Invalid URL encoding: {}", e),
-                );
+                let embed = create_error_response("URL Encoding Error", &e);
                 ctx.send(poise::CreateReply::default().embed(embed)).await?;
                 return Ok(());
             }
@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     sync::Arc,
     time::{Duration, Instant},
 };
@@ -9,7 +9,10 @@ use tokio::sync::RwLock;
 
 use crate::{
     Context, Error,
-    util::command::{check_cooldown, create_success_response},
+    util::{
+        command::{check_cooldown, create_success_response},
+        http_cache::HttpCache,
+    },
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,13 +46,18 @@ struct CachedJep {
 
 lazy_static::lazy_static! {
     static ref JEP_CACHE: Arc<RwLock<HashMap<u16, CachedJep>>> = Arc::new(RwLock::new(HashMap::new()));
+    /// Conditional-GET layer sitting under `JEP_CACHE`. A TTL expiry above
+    /// only means "stop trusting the parsed metadata without asking the
+    /// origin" — this still lets a 304 response skip re-parsing the page.
+    static ref JEP_HTTP_CACHE: HttpCache<JepMetadata> = HttpCache::new();
 }
 
 const CACHE_DURATION: Duration = Duration::from_secs(3600);
 
 #[poise::command(
     slash_command,
-    description_localized("en-US", "Get information about a Java Enhancement Proposal (JEP)")
+    description_localized("en-US", "Get information about a Java Enhancement Proposal (JEP)"),
+    subcommands("jep_search")
 )]
 pub async fn jep(
     ctx: Context<'_>,
@@ -125,18 +133,16 @@ async fn fetch_jep_info(number: u16) -> Result<JepMetadata, Error> {
         .user_agent("Arisa-Bot/1.0")
         .build()?;
 
-    let response = client.get(&url).send().await?;
-
-    if !response.status().is_success() {
-        return Err(crate::error::BotError::GitHub(format!(
-            "JEP {} not found or inaccessible (HTTP {})",
-            number,
-            response.status()
-        )));
-    }
-
-    let html = response.text().await?;
-    parse_jep_html(&html, number)
+    JEP_HTTP_CACHE
+        .get_or_fetch(&client, &url, |html| parse_jep_html(html, number))
+        .await
+        .map_err(|e| match e {
+            crate::error::BotError::Http(msg) => crate::error::BotError::GitHub(format!(
+                "JEP {} not found or inaccessible ({})",
+                number, msg
+            )),
+            other => other,
+        })
 }
 
 fn parse_jep_html(html: &str, number: u16) -> Result<JepMetadata, Error> {
@@ -394,3 +400,398 @@ fn format_jep_description(jep: &JepMetadata, number: u16, detailed: bool) -> Str
     description.push_str(&format!("\n**Link:** https://openjdk.org/jeps/{}", number));
     description
 }
+
+#[poise::command(
+    rename = "search",
+    slash_command,
+    description_localized(
+        "en-US",
+        "Search cached JEPs by free text and field filters (e.g. 'loom status = Closed AND release = 21')"
+    )
+)]
+pub async fn jep_search(
+    ctx: Context<'_>,
+    #[description = "Free-text terms and/or field filters joined with AND/OR"] query: String,
+    #[description = "JEP number range to warm the cache with first, e.g. 400-470"]
+    warm_range: Option<String>,
+) -> Result<(), Error> {
+    check_cooldown(&ctx, "jep", ctx.data().config.cooldowns.github_cooldown).await?;
+    ctx.defer().await?;
+
+    if let Some(range) = warm_range {
+        warm_jep_cache(&range).await?;
+    }
+
+    let ast = match parse_filter_expression(&query) {
+        Ok(ast) => ast,
+        Err(e) => {
+            let embed = create_success_response(
+                "Invalid Search Query",
+                &format!("Could not parse query: {}", e),
+                false,
+                &ctx.data().config,
+            );
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            return Ok(());
+        }
+    };
+
+    let cache = JEP_CACHE.read().await;
+    let snapshot: HashMap<u16, JepMetadata> = cache
+        .iter()
+        .map(|(number, cached)| (*number, cached.metadata.clone()))
+        .collect();
+    drop(cache);
+
+    let index = build_inverted_index(&snapshot);
+    let matches = eval_filter(&ast, &snapshot, &index);
+
+    let terms = collect_free_terms(&ast);
+    let mut ranked: Vec<(u16, usize)> = matches
+        .into_iter()
+        .map(|number| (number, term_overlap_score(number, &terms, &index)))
+        .collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    if ranked.is_empty() {
+        let embed = create_success_response(
+            "JEP Search",
+            &format!("No cached JEPs matched `{}`.", query),
+            false,
+            &ctx.data().config,
+        );
+        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        return Ok(());
+    }
+
+    let mut description = String::new();
+    for (number, score) in ranked.iter().take(15) {
+        let metadata = &snapshot[number];
+        description.push_str(&format!(
+            "**JEP {}: {}**\nStatus: {} | Release: {} (score: {})\n\n",
+            number,
+            metadata.title,
+            metadata.status.as_deref().unwrap_or("Unknown"),
+            metadata.release.as_deref().unwrap_or("Unknown"),
+            score
+        ));
+    }
+
+    let title = format!("JEP Search: {} ({} results)", query, ranked.len());
+    let embed = create_success_response(&title, &description, false, &ctx.data().config);
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
+
+async fn warm_jep_cache(range: &str) -> Result<(), Error> {
+    let (start, end) = range
+        .split_once('-')
+        .and_then(|(a, b)| Some((a.trim().parse::<u16>().ok()?, b.trim().parse::<u16>().ok()?)))
+        .ok_or_else(|| {
+            crate::error::BotError::InvalidFormat(format!(
+                "invalid JEP range '{}', expected e.g. '400-470'",
+                range
+            ))
+        })?;
+
+    if end < start || (end - start) >= 100 {
+        return Err(crate::error::BotError::InvalidFormat(
+            "JEP range must be non-empty and span at most 100 numbers".to_string(),
+        ));
+    }
+
+    for number in start..=end {
+        if get_cached_jep(number).await.is_some() {
+            continue;
+        }
+        if let Ok(metadata) = fetch_jep_info(number).await {
+            cache_jep(number, metadata).await;
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FilterOp {
+    Eq,
+    NotEq,
+    Contains,
+}
+
+#[derive(Debug, Clone)]
+struct Condition {
+    field: String,
+    op: FilterOp,
+    value: String,
+}
+
+#[derive(Debug, Clone)]
+enum FilterNode {
+    And(Box<FilterNode>, Box<FilterNode>),
+    Or(Box<FilterNode>, Box<FilterNode>),
+    Condition(Condition),
+    FreeText(String),
+}
+
+/// Recursive-descent parser for a small filter grammar: free-text terms and
+/// `field = value` / `field != value` / `field contains value` conditions,
+/// joined by `AND`/`OR` (adjacent terms with no connector are implicitly
+/// ANDed), with `(...)` grouping.
+struct FilterParser {
+    tokens: Vec<String>,
+    pos: usize,
+}
+
+fn tokenize_filter(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+
+    for c in input.chars() {
+        match c {
+            '(' | ')' => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                tokens.push(c.to_string());
+            }
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            _ => current.push(c),
+        }
+    }
+
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+fn parse_filter_expression(input: &str) -> Result<FilterNode, String> {
+    let tokens = tokenize_filter(input);
+    if tokens.is_empty() {
+        return Err("empty query".to_string());
+    }
+
+    let mut parser = FilterParser { tokens, pos: 0 };
+    let node = parser.parse_or()?;
+
+    if parser.pos != parser.tokens.len() {
+        return Err(format!("unexpected token '{}'", parser.tokens[parser.pos]));
+    }
+
+    Ok(node)
+}
+
+impl FilterParser {
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.pos).map(String::as_str)
+    }
+
+    fn is_keyword(token: &str, keyword: &str) -> bool {
+        token.eq_ignore_ascii_case(keyword)
+    }
+
+    fn parse_or(&mut self) -> Result<FilterNode, String> {
+        let mut node = self.parse_and()?;
+
+        while let Some(token) = self.peek() {
+            if Self::is_keyword(token, "OR") {
+                self.pos += 1;
+                let rhs = self.parse_and()?;
+                node = FilterNode::Or(Box::new(node), Box::new(rhs));
+            } else {
+                break;
+            }
+        }
+
+        Ok(node)
+    }
+
+    fn parse_and(&mut self) -> Result<FilterNode, String> {
+        let mut node = self.parse_term()?;
+
+        loop {
+            match self.peek() {
+                Some(token) if Self::is_keyword(token, "AND") => {
+                    self.pos += 1;
+                    let rhs = self.parse_term()?;
+                    node = FilterNode::And(Box::new(node), Box::new(rhs));
+                }
+                Some(token) if Self::is_keyword(token, "OR") || token == ")" => break,
+                Some(_) => {
+                    let rhs = self.parse_term()?;
+                    node = FilterNode::And(Box::new(node), Box::new(rhs));
+                }
+                None => break,
+            }
+        }
+
+        Ok(node)
+    }
+
+    fn parse_term(&mut self) -> Result<FilterNode, String> {
+        match self.peek() {
+            Some("(") => {
+                self.pos += 1;
+                let node = self.parse_or()?;
+                match self.peek() {
+                    Some(")") => self.pos += 1,
+                    _ => return Err("expected closing ')'".to_string()),
+                }
+                Ok(node)
+            }
+            Some(_) => self.parse_condition_or_term(),
+            None => Err("unexpected end of query".to_string()),
+        }
+    }
+
+    fn parse_condition_or_term(&mut self) -> Result<FilterNode, String> {
+        let field = self.tokens[self.pos].clone();
+
+        if let Some(op_token) = self.tokens.get(self.pos + 1) {
+            let op = if op_token == "=" {
+                Some(FilterOp::Eq)
+            } else if op_token == "!=" {
+                Some(FilterOp::NotEq)
+            } else if Self::is_keyword(op_token, "contains") {
+                Some(FilterOp::Contains)
+            } else {
+                None
+            };
+
+            if let Some(op) = op {
+                let value = self
+                    .tokens
+                    .get(self.pos + 2)
+                    .ok_or_else(|| format!("missing value for field '{}'", field))?
+                    .clone();
+                self.pos += 3;
+                return Ok(FilterNode::Condition(Condition {
+                    field: field.to_lowercase(),
+                    op,
+                    value,
+                }));
+            }
+        }
+
+        self.pos += 1;
+        Ok(FilterNode::FreeText(field.to_lowercase()))
+    }
+}
+
+fn tokenize_text(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+        .collect()
+}
+
+fn build_inverted_index(cache: &HashMap<u16, JepMetadata>) -> HashMap<String, HashSet<u16>> {
+    let mut index: HashMap<String, HashSet<u16>> = HashMap::new();
+
+    for (&number, metadata) in cache {
+        let mut fields = vec![metadata.title.clone()];
+        if let Some(summary) = &metadata.summary {
+            fields.push(summary.clone());
+        }
+        if let Some(goals) = &metadata.goals {
+            fields.extend(goals.iter().cloned());
+        }
+        if let Some(status) = &metadata.status {
+            fields.push(status.clone());
+        }
+        if let Some(release) = &metadata.release {
+            fields.push(release.clone());
+        }
+        if let Some(jep_type) = &metadata.jep_type {
+            fields.push(jep_type.clone());
+        }
+        if let Some(component) = &metadata.component {
+            fields.push(component.clone());
+        }
+
+        for field in fields {
+            for token in tokenize_text(&field) {
+                index.entry(token).or_default().insert(number);
+            }
+        }
+    }
+
+    index
+}
+
+fn field_value<'a>(metadata: &'a JepMetadata, field: &str) -> Option<&'a str> {
+    match field {
+        "title" => Some(metadata.title.as_str()),
+        "status" => metadata.status.as_deref(),
+        "release" => metadata.release.as_deref(),
+        "type" => metadata.jep_type.as_deref(),
+        "scope" => metadata.scope.as_deref(),
+        "component" => metadata.component.as_deref(),
+        "author" => metadata.author.as_deref(),
+        "owner" => metadata.owner.as_deref(),
+        "summary" => metadata.summary.as_deref(),
+        _ => None,
+    }
+}
+
+fn eval_filter(
+    node: &FilterNode,
+    cache: &HashMap<u16, JepMetadata>,
+    index: &HashMap<String, HashSet<u16>>,
+) -> HashSet<u16> {
+    match node {
+        FilterNode::And(lhs, rhs) => {
+            let left = eval_filter(lhs, cache, index);
+            let right = eval_filter(rhs, cache, index);
+            left.intersection(&right).copied().collect()
+        }
+        FilterNode::Or(lhs, rhs) => {
+            let left = eval_filter(lhs, cache, index);
+            let right = eval_filter(rhs, cache, index);
+            left.union(&right).copied().collect()
+        }
+        FilterNode::FreeText(term) => index.get(term).cloned().unwrap_or_default(),
+        FilterNode::Condition(condition) => cache
+            .iter()
+            .filter(|(_, metadata)| match_condition(condition, metadata))
+            .map(|(&number, _)| number)
+            .collect(),
+    }
+}
+
+fn match_condition(condition: &Condition, metadata: &JepMetadata) -> bool {
+    let Some(actual) = field_value(metadata, &condition.field) else {
+        return condition.op == FilterOp::NotEq;
+    };
+
+    match condition.op {
+        FilterOp::Eq => actual.eq_ignore_ascii_case(&condition.value),
+        FilterOp::NotEq => !actual.eq_ignore_ascii_case(&condition.value),
+        FilterOp::Contains => actual.to_lowercase().contains(&condition.value.to_lowercase()),
+    }
+}
+
+fn collect_free_terms(node: &FilterNode) -> Vec<String> {
+    match node {
+        FilterNode::And(lhs, rhs) | FilterNode::Or(lhs, rhs) => {
+            let mut terms = collect_free_terms(lhs);
+            terms.extend(collect_free_terms(rhs));
+            terms
+        }
+        FilterNode::FreeText(term) => vec![term.clone()],
+        FilterNode::Condition(_) => Vec::new(),
+    }
+}
+
+fn term_overlap_score(number: u16, terms: &[String], index: &HashMap<String, HashSet<u16>>) -> usize {
+    terms
+        .iter()
+        .filter(|term| index.get(*term).is_some_and(|ids| ids.contains(&number)))
+        .count()
+}
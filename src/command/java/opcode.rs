@@ -11,7 +11,10 @@ use tokio::sync::RwLock;
 
 use crate::{
     Context, Error,
-    util::command::{check_cooldown, create_success_response},
+    util::{
+        command::{check_cooldown, create_success_response},
+        dot::{DotBuilder, Kind},
+    },
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -73,14 +76,20 @@ async fn autocomplete_opcode(
 
 #[poise::command(
     slash_command,
-    description_localized("en-US", "Get information about a JVM bytecode instruction")
+    description_localized(
+        "en-US",
+        "Get information about a JVM bytecode instruction, or disassemble a raw bytecode stream"
+    )
 )]
 pub async fn opcode(
     ctx: Context<'_>,
     #[description = "JVM instruction name (e.g., aaload, bipush, invokevirtual)"]
     #[autocomplete = "autocomplete_opcode"]
-    instruction: String,
+    instruction: Option<String>,
+    #[description = "Hex-encoded raw JVM bytecode to disassemble"] bytecode: Option<String>,
     #[description = "Show detailed stack information"] detailed: Option<bool>,
+    #[description = "Render the operand stack transition as Graphviz DOT instead of text"]
+    graph: Option<bool>,
 ) -> Result<(), Error> {
     check_cooldown(
         &ctx,
@@ -89,7 +98,33 @@ pub async fn opcode(
     )
     .await?;
 
+    let provided = [instruction.is_some(), bytecode.is_some()]
+        .iter()
+        .filter(|p| **p)
+        .count();
+
+    if provided == 0 {
+        return Err(crate::error::BotError::InvalidFormat(
+            "Provide either an `instruction` to look up or `bytecode` to disassemble.".to_string(),
+        ));
+    }
+
+    if provided > 1 {
+        return Err(crate::error::BotError::InvalidFormat(
+            "Provide only one of `instruction` or `bytecode`.".to_string(),
+        ));
+    }
+
     let instructions = get_cached_instructions().await?;
+
+    if let Some(bytecode) = bytecode {
+        let disassembly = disassemble_bytecode(&bytecode, &instructions)?;
+        let embed = create_success_response("JVM Disassembly", &disassembly, true, &ctx.data().config);
+        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        return Ok(());
+    }
+
+    let instruction = instruction.expect("exactly one of instruction/bytecode is set");
     let instruction_key = instruction.trim().to_lowercase();
 
     let jvm_instruction = instructions.get(&instruction_key).ok_or_else(|| {
@@ -100,6 +135,14 @@ pub async fn opcode(
         ))
     })?;
 
+    if graph.unwrap_or(false) {
+        let dot = stack_transition_dot(jvm_instruction);
+        let title = format!("JVM Instruction: {} (graph)", jvm_instruction.mnemonic);
+        let embed = create_success_response(&title, &dot, true, &ctx.data().config);
+        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        return Ok(());
+    }
+
     let show_detailed = detailed.unwrap_or(false);
     let description = format_instruction_info(jvm_instruction, show_detailed);
 
@@ -110,6 +153,21 @@ pub async fn opcode(
     Ok(())
 }
 
+/// Renders the instruction's stack transition as a two-node digraph:
+/// `operandStackBefore` -> `operandStackAfter`, with the edge labeled by
+/// the mnemonic/operation so it reads like a single step of execution.
+fn stack_transition_dot(instruction: &JvmInstruction) -> String {
+    let mut dot = DotBuilder::new(Kind::Digraph, &format!("{}_stack", instruction.mnemonic));
+    dot.node("before", &instruction.operand_stack_before);
+    dot.node("after", &instruction.operand_stack_after);
+    dot.edge(
+        "before",
+        "after",
+        &format!("{}: {}", instruction.mnemonic, instruction.operation),
+    );
+    dot.build()
+}
+
 async fn get_cached_instructions() -> Result<HashMap<String, JvmInstruction>, Error> {
     let cache = INSTRUCTION_CACHE.read().await;
 
@@ -205,3 +263,225 @@ fn extract_opcode_number(opcode_str: &str) -> Option<OpcodeInfo> {
     }
     None
 }
+
+/// Maps each numeric opcode (0-255) to its `JvmInstruction`, built fresh
+/// from the already-cached lookup table so the disassembler shares it
+/// instead of re-parsing `JSON_DATA`.
+fn build_opcode_table(instructions: &HashMap<String, JvmInstruction>) -> HashMap<u8, JvmInstruction> {
+    let mut table = HashMap::new();
+    for instruction in instructions.values() {
+        if let Some(opcode_str) = &instruction.opcode {
+            if let Some(info) = extract_opcode_number(opcode_str) {
+                table.entry(info.decimal).or_insert_with(|| instruction.clone());
+            }
+        }
+    }
+    table
+}
+
+/// Most instruction `format` strings are the mnemonic followed by one
+/// operand token per byte consumed (`invokevirtual indexbyte1 indexbyte2`
+/// is 2 bytes, `bipush byte` is 1). `tableswitch`/`lookupswitch` and `wide`
+/// don't fit this shape and are special-cased in `disassemble_bytecode`
+/// instead of going through this heuristic.
+fn operand_length(format: &str) -> usize {
+    format.split_whitespace().skip(1).count()
+}
+
+fn read_operand(bytes: &[u8], start: usize, len: usize) -> Vec<u8> {
+    if start >= bytes.len() {
+        return Vec::new();
+    }
+    let end = (start + len).min(bytes.len());
+    bytes[start..end].to_vec()
+}
+
+/// Renders an operand as a single big-endian hex value rather than
+/// space-separated bytes, so a 2-byte index operand reads as `0x00AB`
+/// instead of `0x00 0xAB`.
+fn format_operand(operand: &[u8]) -> String {
+    if operand.is_empty() {
+        return String::new();
+    }
+    let value = operand.iter().fold(0u32, |acc, &b| (acc << 8) | b as u32);
+    format!("0x{:0width$X}", value, width = operand.len() * 2)
+}
+
+/// Walks a raw hex-encoded JVM bytecode stream, decoding one instruction at
+/// a time. Unknown opcode bytes render as `.byte 0xNN` and decoding
+/// continues rather than aborting.
+fn disassemble_bytecode(
+    hex_input: &str,
+    instructions: &HashMap<String, JvmInstruction>,
+) -> Result<String, Error> {
+    let bytes = hex::decode(hex_input.trim())
+        .map_err(|e| crate::error::BotError::InvalidFormat(format!("Invalid hex bytecode: {}", e)))?;
+
+    if bytes.is_empty() {
+        return Err(crate::error::BotError::InvalidFormat(
+            "No bytecode to disassemble.".to_string(),
+        ));
+    }
+
+    let table = build_opcode_table(instructions);
+    let mut lines = Vec::new();
+    let mut offset = 0usize;
+
+    while offset < bytes.len() {
+        let opcode_byte = bytes[offset];
+
+        let Some(instruction) = table.get(&opcode_byte) else {
+            lines.push(format!("{:4}: .byte 0x{:02X}", offset, opcode_byte));
+            offset += 1;
+            continue;
+        };
+
+        if instruction.mnemonic.eq_ignore_ascii_case("wide") {
+            let wide_offset = offset;
+            offset += 1;
+
+            let Some(&modified_opcode) = bytes.get(offset) else {
+                lines.push(format!("{:4}: wide", wide_offset));
+                break;
+            };
+
+            let Some(modified) = table.get(&modified_opcode) else {
+                lines.push(format!("{:4}: wide", wide_offset));
+                lines.push(format!("{:4}: .byte 0x{:02X}", offset, modified_opcode));
+                offset += 1;
+                continue;
+            };
+
+            // `wide` doubles the index operand to 2 bytes; ahead of `iinc`
+            // it additionally widens the constant operand to 2 bytes.
+            let operand_len = if modified.mnemonic.eq_ignore_ascii_case("iinc") {
+                4
+            } else {
+                2
+            };
+            let operand_bytes = read_operand(&bytes, offset + 1, operand_len);
+
+            lines.push(format!(
+                "{:4}: wide {} {}",
+                wide_offset,
+                modified.mnemonic,
+                format_operand(&operand_bytes)
+            ));
+
+            offset += 1 + operand_bytes.len();
+            continue;
+        }
+
+        if instruction.mnemonic.eq_ignore_ascii_case("tableswitch")
+            || instruction.mnemonic.eq_ignore_ascii_case("lookupswitch")
+        {
+            let (consumed, rendered) = disassemble_switch(&instruction.mnemonic, &bytes, offset);
+            lines.push(format!("{:4}: {}", offset, rendered));
+            offset += consumed.max(1);
+            continue;
+        }
+
+        let operand_len = operand_length(&instruction.format);
+        let operand_bytes = read_operand(&bytes, offset + 1, operand_len);
+
+        if operand_bytes.is_empty() {
+            lines.push(format!("{:4}: {}", offset, instruction.mnemonic));
+        } else {
+            lines.push(format!(
+                "{:4}: {} {}",
+                offset,
+                instruction.mnemonic,
+                format_operand(&operand_bytes)
+            ));
+        }
+
+        offset += 1 + operand_bytes.len();
+    }
+
+    Ok(lines.join("\n"))
+}
+
+/// `tableswitch`/`lookupswitch` pad to the next 4-byte boundary measured
+/// from the start of the instruction stream, then carry a variable number
+/// of 4-byte entries the generic `operand_length` heuristic can't express.
+/// Returns `(bytes consumed including the opcode, rendered line)`.
+fn disassemble_switch(mnemonic: &str, bytes: &[u8], opcode_offset: usize) -> (usize, String) {
+    let after_opcode = opcode_offset + 1;
+    let padding = (4 - (after_opcode % 4)) % 4;
+    let default_start = after_opcode + padding;
+
+    let default_bytes = read_operand(bytes, default_start, 4);
+    if default_bytes.len() < 4 {
+        return (bytes.len() - opcode_offset, format!("{} <truncated>", mnemonic));
+    }
+    let default_offset = i32::from_be_bytes(default_bytes.try_into().unwrap());
+
+    if mnemonic.eq_ignore_ascii_case("tableswitch") {
+        let low_bytes = read_operand(bytes, default_start + 4, 4);
+        let high_bytes = read_operand(bytes, default_start + 8, 4);
+        if low_bytes.len() < 4 || high_bytes.len() < 4 {
+            return (bytes.len() - opcode_offset, format!("{} <truncated>", mnemonic));
+        }
+
+        let low = i32::from_be_bytes(low_bytes.try_into().unwrap());
+        let high = i32::from_be_bytes(high_bytes.try_into().unwrap());
+        let entry_count = if high >= low { (high - low + 1) as usize } else { 0 };
+
+        let entries_start = default_start + 12;
+        let mut offsets = Vec::new();
+        for i in 0..entry_count {
+            let entry = read_operand(bytes, entries_start + i * 4, 4);
+            if entry.len() < 4 {
+                break;
+            }
+            offsets.push(i32::from_be_bytes(entry.try_into().unwrap()));
+        }
+
+        let consumed = (entries_start + offsets.len() * 4) - opcode_offset;
+        let rendered = format!(
+            "tableswitch default={} low={} high={} offsets=[{}]",
+            default_offset,
+            low,
+            high,
+            offsets
+                .iter()
+                .map(|o| o.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        (consumed, rendered)
+    } else {
+        let npairs_bytes = read_operand(bytes, default_start + 4, 4);
+        if npairs_bytes.len() < 4 {
+            return (bytes.len() - opcode_offset, format!("{} <truncated>", mnemonic));
+        }
+        let npairs = i32::from_be_bytes(npairs_bytes.try_into().unwrap()).max(0) as usize;
+
+        let pairs_start = default_start + 8;
+        let mut pairs = Vec::new();
+        for i in 0..npairs {
+            let match_bytes = read_operand(bytes, pairs_start + i * 8, 4);
+            let offset_bytes = read_operand(bytes, pairs_start + i * 8 + 4, 4);
+            if match_bytes.len() < 4 || offset_bytes.len() < 4 {
+                break;
+            }
+            pairs.push((
+                i32::from_be_bytes(match_bytes.try_into().unwrap()),
+                i32::from_be_bytes(offset_bytes.try_into().unwrap()),
+            ));
+        }
+
+        let consumed = (pairs_start + pairs.len() * 8) - opcode_offset;
+        let rendered = format!(
+            "lookupswitch default={} npairs={} pairs=[{}]",
+            default_offset,
+            npairs,
+            pairs
+                .iter()
+                .map(|(m, o)| format!("{}:{}", m, o))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        (consumed, rendered)
+    }
+}
@@ -9,7 +9,8 @@ use crate::{
 )]
 pub async fn color(
     ctx: Context<'_>,
-    #[description = "Color in HEX (#FF0000), RGB (255,0,0), or name (red)"] input: String,
+    #[description = "Color in HEX (#FF0000), RGB (255,0,0), or name (red); add alpha for transparency"]
+    input: String,
 ) -> Result<(), Error> {
     let input = input.trim();
 
@@ -17,8 +18,10 @@ pub async fn color(
         Ok(c) => c,
         Err(e) => {
             let error_msg = format!(
-                "{}\n\nSupported formats:\n• HEX: #FF0000 or FF0000\n• RGB: rgb(255, 0, 0) or \
-                 255,0,0\n• HSL: hsl(0, 100%, 50%)\n• Color names: red, blue, green, etc.",
+                "{}\n\nSupported formats:\n• HEX: #FF0000, FF0000, #F00A (RGBA), or #FF0000AA \
+                 (RRGGBBAA)\n• RGB: rgb(255, 0, 0), rgba(255, 0, 0, 0.5), or 255,0,0,0.5\n• HSL: \
+                 hsl(0, 100%, 50%) or hsla(0, 100%, 50%, 0.5)\n• X11: rgb:ff/80/00 (1-4 hex \
+                 digits per component)\n• Color names: red, blue, green, etc.",
                 e
             );
             let embed = create_error_response("Invalid Color Format", &error_msg);
@@ -27,16 +30,34 @@ pub async fn color(
         }
     };
 
-    let hex = format!("#{:02X}{:02X}{:02X}", color.r, color.g, color.b);
-    let rgb = format!("rgb({}, {}, {})", color.r, color.g, color.b);
+    let has_alpha = color.a != 255;
+
+    let hex = if has_alpha {
+        format!("#{:02X}{:02X}{:02X}{:02X}", color.r, color.g, color.b, color.a)
+    } else {
+        format!("#{:02X}{:02X}{:02X}", color.r, color.g, color.b)
+    };
+    let alpha_fraction = color.a as f32 / 255.0;
+    let rgb = if has_alpha {
+        format!(
+            "rgba({}, {}, {}, {:.2})",
+            color.r, color.g, color.b, alpha_fraction
+        )
+    } else {
+        format!("rgb({}, {}, {})", color.r, color.g, color.b)
+    };
     let hsl = rgb_to_hsl(color.r, color.g, color.b);
-    let hsl_str = format!("hsl({}, {}%, {}%)", hsl.0, hsl.1, hsl.2);
+    let hsl_str = if has_alpha {
+        format!("hsla({}, {}%, {}%, {:.2})", hsl.0, hsl.1, hsl.2, alpha_fraction)
+    } else {
+        format!("hsl({}, {}%, {}%)", hsl.0, hsl.1, hsl.2)
+    };
     let hsv = rgb_to_hsv(color.r, color.g, color.b);
     let hsv_str = format!("hsv({}, {}%, {}%)", hsv.0, hsv.1, hsv.2);
     let cmyk = rgb_to_cmyk(color.r, color.g, color.b);
     let cmyk_str = format!("cmyk({}%, {}%, {}%, {}%)", cmyk.0, cmyk.1, cmyk.2, cmyk.3);
 
-    let description = format!(
+    let mut description = format!(
         "**Color Formats:**\n**HEX:** `{}`\n**RGB:** `{}`\n**HSL:** `{}`\n**HSV:** \
          `{}`\n**CMYK:** `{}`\n\n**Values:**\n**Decimal:** `{}`\n**CSS:** `{}`\n**Int:** `{}`",
         hex,
@@ -49,8 +70,21 @@ pub async fn color(
         (color.r as u32) << 16 | (color.g as u32) << 8 | color.b as u32
     );
 
+    if has_alpha {
+        description.push_str(&format!(
+            "\n**Alpha:** `{}` ({:.0}% opaque)",
+            color.a,
+            alpha_fraction * 100.0
+        ));
+    }
+
+    description.push_str(&format!(
+        "\n**Nearest Named Color:** `{}`",
+        nearest_named_color(&color)
+    ));
+
     let title = format!("Color: {}", hex);
-    let embed = create_success_response(&title, &description, false);
+    let embed = create_success_response(&title, &description, false, &ctx.data().config);
 
     ctx.send(poise::CreateReply::default().embed(embed)).await?;
     Ok(())
@@ -61,6 +95,30 @@ struct Color {
     r: u8,
     g: u8,
     b: u8,
+    a: u8,
+}
+
+impl Color {
+    fn opaque(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 255 }
+    }
+}
+
+/// Parses a CSS-style alpha component, accepting either a `0.0..=1.0`
+/// fraction (as CSS uses) or a bare `0..=255` byte value, since users type
+/// both interchangeably.
+fn parse_alpha(raw: &str) -> Result<u8, String> {
+    let value: f32 = raw.parse().map_err(|_| "Invalid alpha value".to_string())?;
+    if value < 0.0 {
+        return Err("Alpha value must not be negative".to_string());
+    }
+    if value <= 1.0 {
+        Ok((value * 255.0).round() as u8)
+    } else if value <= 255.0 {
+        Ok(value.round() as u8)
+    } else {
+        Err("Alpha value must be between 0 and 1, or 0 and 255".to_string())
+    }
 }
 
 fn parse_color(input: &str) -> Result<Color, String> {
@@ -73,47 +131,130 @@ fn parse_color(input: &str) -> Result<Color, String> {
         return parse_hex_color(&input);
     }
 
-    if input.starts_with("rgb(") && input.ends_with(')') {
+    if let Some(device_spec) = input.strip_prefix("rgb:") {
+        return parse_x11_rgb_color(device_spec);
+    }
+
+    if (input.starts_with("rgb(") || input.starts_with("rgba(")) && input.ends_with(')') {
         return parse_rgb_color(&input);
     }
 
+    if (input.starts_with("hsl(") || input.starts_with("hsla(")) && input.ends_with(')') {
+        return parse_hsl_color(&input);
+    }
+
     if input.contains(',') {
         return parse_comma_rgb(&input);
     }
 
-    if input.starts_with("hsl(") && input.ends_with(')') {
-        return parse_hsl_color(&input);
+    Err("Unrecognized color format".to_string())
+}
+
+/// Decodes a single hex digit (`0-9A-Fa-f`) to its value without a
+/// match/if-else ladder over every possible byte, using arithmetic on the
+/// three range checks instead. Returns `None` outside that alphabet.
+const fn decode_hex_nibble(c: u8) -> Option<u8> {
+    let is_digit = (c.wrapping_sub(b'0') < 10) as u8;
+    let is_upper = (c.wrapping_sub(b'A') < 6) as u8;
+    let is_lower = (c.wrapping_sub(b'a') < 6) as u8;
+
+    let value = is_digit * c.wrapping_sub(b'0')
+        + is_upper * c.wrapping_sub(b'A').wrapping_add(10)
+        + is_lower * c.wrapping_sub(b'a').wrapping_add(10);
+
+    if is_digit | is_upper | is_lower == 1 {
+        Some(value)
+    } else {
+        None
     }
+}
 
-    Err("Unrecognized color format".to_string())
+fn invalid_hex_digit(byte: u8, position: usize) -> String {
+    format!("invalid hex digit '{}' at position {}", byte as char, position + 1)
+}
+
+/// Decodes a single-digit shorthand component (`#F00` style), where the
+/// digit is replicated into both nibbles of the byte.
+fn decode_hex_shorthand(bytes: &[u8], index: usize) -> Result<u8, String> {
+    let nibble =
+        decode_hex_nibble(bytes[index]).ok_or_else(|| invalid_hex_digit(bytes[index], index))?;
+    Ok(nibble * 0x11)
+}
+
+/// Decodes a two-digit component (`#FF0000` style) starting at `index`.
+fn decode_hex_byte_at(bytes: &[u8], index: usize) -> Result<u8, String> {
+    let hi =
+        decode_hex_nibble(bytes[index]).ok_or_else(|| invalid_hex_digit(bytes[index], index))?;
+    let lo = decode_hex_nibble(bytes[index + 1])
+        .ok_or_else(|| invalid_hex_digit(bytes[index + 1], index + 1))?;
+    Ok((hi << 4) | lo)
 }
 
 fn parse_hex_color(input: &str) -> Result<Color, String> {
     let hex = input.strip_prefix('#').unwrap_or(input);
-
-    match hex.len() {
-        3 => {
-            let r =
-                u8::from_str_radix(&hex[0..1].repeat(2), 16).map_err(|_| "Invalid hex digit")?;
-            let g =
-                u8::from_str_radix(&hex[1..2].repeat(2), 16).map_err(|_| "Invalid hex digit")?;
-            let b =
-                u8::from_str_radix(&hex[2..3].repeat(2), 16).map_err(|_| "Invalid hex digit")?;
-            Ok(Color { r, g, b })
+    let bytes = hex.as_bytes();
+
+    match bytes.len() {
+        3 | 4 => {
+            let r = decode_hex_shorthand(bytes, 0)?;
+            let g = decode_hex_shorthand(bytes, 1)?;
+            let b = decode_hex_shorthand(bytes, 2)?;
+            let a = if bytes.len() == 4 {
+                decode_hex_shorthand(bytes, 3)?
+            } else {
+                255
+            };
+            Ok(Color { r, g, b, a })
         }
-        6 => {
-            let r = u8::from_str_radix(&hex[0..2], 16).map_err(|_| "Invalid hex digit")?;
-            let g = u8::from_str_radix(&hex[2..4], 16).map_err(|_| "Invalid hex digit")?;
-            let b = u8::from_str_radix(&hex[4..6], 16).map_err(|_| "Invalid hex digit")?;
-            Ok(Color { r, g, b })
+        6 | 8 => {
+            let r = decode_hex_byte_at(bytes, 0)?;
+            let g = decode_hex_byte_at(bytes, 2)?;
+            let b = decode_hex_byte_at(bytes, 4)?;
+            let a = if bytes.len() == 8 {
+                decode_hex_byte_at(bytes, 6)?
+            } else {
+                255
+            };
+            Ok(Color { r, g, b, a })
         }
-        _ => Err("Hex color must be 3 or 6 characters".to_string()),
+        _ => Err("Hex color must be 3, 4, 6, or 8 characters".to_string()),
+    }
+}
+
+/// Parses the X11 `rgb:RR/GG/BB` device-color syntax (e.g. from `xdotool`
+/// or an `.Xresources` file), where each component is 1-4 hex digits that
+/// get scaled from their own bit depth to 8 bits rather than truncated.
+fn parse_x11_rgb_color(spec: &str) -> Result<Color, String> {
+    let parts: Vec<&str> = spec.split('/').collect();
+    if parts.len() != 3 {
+        return Err("X11 rgb: format requires 3 components separated by '/'".to_string());
+    }
+
+    let r = parse_x11_component(parts[0])?;
+    let g = parse_x11_component(parts[1])?;
+    let b = parse_x11_component(parts[2])?;
+
+    Ok(Color::opaque(r, g, b))
+}
+
+fn parse_x11_component(digits: &str) -> Result<u8, String> {
+    if digits.is_empty() || digits.len() > 4 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!(
+            "X11 component '{}' must be 1-4 hex digits",
+            digits
+        ));
     }
+
+    let value = u32::from_str_radix(digits, 16).map_err(|_| "Invalid hex digit")?;
+    let max = 16u32.pow(digits.len() as u32) - 1;
+
+    Ok(((value * 255 + max / 2) / max) as u8)
 }
 
 fn parse_rgb_color(input: &str) -> Result<Color, String> {
     let content = input
-        .strip_prefix("rgb(")
+        .strip_prefix("rgba(")
+        .or_else(|| input.strip_prefix("rgb("))
         .unwrap()
         .strip_suffix(')')
         .unwrap();
@@ -123,20 +264,25 @@ fn parse_rgb_color(input: &str) -> Result<Color, String> {
 fn parse_comma_rgb(input: &str) -> Result<Color, String> {
     let parts: Vec<&str> = input.split(',').map(|s| s.trim()).collect();
 
-    if parts.len() != 3 {
-        return Err("RGB format requires 3 values".to_string());
+    if parts.len() != 3 && parts.len() != 4 {
+        return Err("RGB format requires 3 values, or 4 with alpha".to_string());
     }
 
     let r = parts[0].parse::<u8>().map_err(|_| "Invalid red value")?;
     let g = parts[1].parse::<u8>().map_err(|_| "Invalid green value")?;
     let b = parts[2].parse::<u8>().map_err(|_| "Invalid blue value")?;
+    let a = match parts.get(3) {
+        Some(raw) => parse_alpha(raw)?,
+        None => 255,
+    };
 
-    Ok(Color { r, g, b })
+    Ok(Color { r, g, b, a })
 }
 
 fn parse_hsl_color(input: &str) -> Result<Color, String> {
     let content = input
-        .strip_prefix("hsl(")
+        .strip_prefix("hsla(")
+        .or_else(|| input.strip_prefix("hsl("))
         .unwrap()
         .strip_suffix(')')
         .unwrap();
@@ -145,8 +291,8 @@ fn parse_hsl_color(input: &str) -> Result<Color, String> {
         .map(|s| s.trim().trim_end_matches('%'))
         .collect();
 
-    if parts.len() != 3 {
-        return Err("HSL format requires 3 values".to_string());
+    if parts.len() != 3 && parts.len() != 4 {
+        return Err("HSL format requires 3 values, or 4 with alpha".to_string());
     }
 
     let h = parts[0].parse::<f32>().map_err(|_| "Invalid hue value")?;
@@ -158,82 +304,193 @@ fn parse_hsl_color(input: &str) -> Result<Color, String> {
         .parse::<f32>()
         .map_err(|_| "Invalid lightness value")?
         / 100.0;
+    let a = match parts.get(3) {
+        Some(raw) => parse_alpha(raw)?,
+        None => 255,
+    };
 
     let (r, g, b) = hsl_to_rgb(h, s, l);
-    Ok(Color { r, g, b })
+    Ok(Color { r, g, b, a })
 }
 
+/// The CSS Color Module Level 4 extended (X11) named-color keyword table,
+/// including British/American spelling pairs and `rebeccapurple`.
+const NAMED_COLORS: &[(&str, u8, u8, u8)] = &[
+    ("aliceblue", 240, 248, 255),
+    ("antiquewhite", 250, 235, 215),
+    ("aqua", 0, 255, 255),
+    ("aquamarine", 127, 255, 212),
+    ("azure", 240, 255, 255),
+    ("beige", 245, 245, 220),
+    ("bisque", 255, 228, 196),
+    ("black", 0, 0, 0),
+    ("blanchedalmond", 255, 235, 205),
+    ("blue", 0, 0, 255),
+    ("blueviolet", 138, 43, 226),
+    ("brown", 165, 42, 42),
+    ("burlywood", 222, 184, 135),
+    ("cadetblue", 95, 158, 160),
+    ("chartreuse", 127, 255, 0),
+    ("chocolate", 210, 105, 30),
+    ("coral", 255, 127, 80),
+    ("cornflowerblue", 100, 149, 237),
+    ("cornsilk", 255, 248, 220),
+    ("crimson", 220, 20, 60),
+    ("cyan", 0, 255, 255),
+    ("darkblue", 0, 0, 139),
+    ("darkcyan", 0, 139, 139),
+    ("darkgoldenrod", 184, 134, 11),
+    ("darkgray", 169, 169, 169),
+    ("darkgreen", 0, 100, 0),
+    ("darkgrey", 169, 169, 169),
+    ("darkkhaki", 189, 183, 107),
+    ("darkmagenta", 139, 0, 139),
+    ("darkolivegreen", 85, 107, 47),
+    ("darkorange", 255, 140, 0),
+    ("darkorchid", 153, 50, 204),
+    ("darkred", 139, 0, 0),
+    ("darksalmon", 233, 150, 122),
+    ("darkseagreen", 143, 188, 143),
+    ("darkslateblue", 72, 61, 139),
+    ("darkslategray", 47, 79, 79),
+    ("darkslategrey", 47, 79, 79),
+    ("darkturquoise", 0, 206, 209),
+    ("darkviolet", 148, 0, 211),
+    ("deeppink", 255, 20, 147),
+    ("deepskyblue", 0, 191, 255),
+    ("dimgray", 105, 105, 105),
+    ("dimgrey", 105, 105, 105),
+    ("dodgerblue", 30, 144, 255),
+    ("firebrick", 178, 34, 34),
+    ("floralwhite", 255, 250, 240),
+    ("forestgreen", 34, 139, 34),
+    ("fuchsia", 255, 0, 255),
+    ("gainsboro", 220, 220, 220),
+    ("ghostwhite", 248, 248, 255),
+    ("gold", 255, 215, 0),
+    ("goldenrod", 218, 165, 32),
+    ("gray", 128, 128, 128),
+    ("green", 0, 128, 0),
+    ("greenyellow", 173, 255, 47),
+    ("grey", 128, 128, 128),
+    ("honeydew", 240, 255, 240),
+    ("hotpink", 255, 105, 180),
+    ("indianred", 205, 92, 92),
+    ("indigo", 75, 0, 130),
+    ("ivory", 255, 255, 240),
+    ("khaki", 240, 230, 140),
+    ("lavender", 230, 230, 250),
+    ("lavenderblush", 255, 240, 245),
+    ("lawngreen", 124, 252, 0),
+    ("lemonchiffon", 255, 250, 205),
+    ("lightblue", 173, 216, 230),
+    ("lightcoral", 240, 128, 128),
+    ("lightcyan", 224, 255, 255),
+    ("lightgoldenrodyellow", 250, 250, 210),
+    ("lightgray", 211, 211, 211),
+    ("lightgreen", 144, 238, 144),
+    ("lightgrey", 211, 211, 211),
+    ("lightpink", 255, 182, 193),
+    ("lightsalmon", 255, 160, 122),
+    ("lightseagreen", 32, 178, 170),
+    ("lightskyblue", 135, 206, 250),
+    ("lightslategray", 119, 136, 153),
+    ("lightslategrey", 119, 136, 153),
+    ("lightsteelblue", 176, 196, 222),
+    ("lightyellow", 255, 255, 224),
+    ("lime", 0, 255, 0),
+    ("limegreen", 50, 205, 50),
+    ("linen", 250, 240, 230),
+    ("magenta", 255, 0, 255),
+    ("maroon", 128, 0, 0),
+    ("mediumaquamarine", 102, 205, 170),
+    ("mediumblue", 0, 0, 205),
+    ("mediumorchid", 186, 85, 211),
+    ("mediumpurple", 147, 112, 219),
+    ("mediumseagreen", 60, 179, 113),
+    ("mediumslateblue", 123, 104, 238),
+    ("mediumspringgreen", 0, 250, 154),
+    ("mediumturquoise", 72, 209, 204),
+    ("mediumvioletred", 199, 21, 133),
+    ("midnightblue", 25, 25, 112),
+    ("mintcream", 245, 255, 250),
+    ("mistyrose", 255, 228, 225),
+    ("moccasin", 255, 228, 181),
+    ("navajowhite", 255, 222, 173),
+    ("navy", 0, 0, 128),
+    ("oldlace", 253, 245, 230),
+    ("olive", 128, 128, 0),
+    ("olivedrab", 107, 142, 35),
+    ("orange", 255, 165, 0),
+    ("orangered", 255, 69, 0),
+    ("orchid", 218, 112, 214),
+    ("palegoldenrod", 238, 232, 170),
+    ("palegreen", 152, 251, 152),
+    ("paleturquoise", 175, 238, 238),
+    ("palevioletred", 219, 112, 147),
+    ("papayawhip", 255, 239, 213),
+    ("peachpuff", 255, 218, 185),
+    ("peru", 205, 133, 63),
+    ("pink", 255, 192, 203),
+    ("plum", 221, 160, 221),
+    ("powderblue", 176, 224, 230),
+    ("purple", 128, 0, 128),
+    ("rebeccapurple", 102, 51, 153),
+    ("red", 255, 0, 0),
+    ("rosybrown", 188, 143, 143),
+    ("royalblue", 65, 105, 225),
+    ("saddlebrown", 139, 69, 19),
+    ("salmon", 250, 128, 114),
+    ("sandybrown", 244, 164, 96),
+    ("seagreen", 46, 139, 87),
+    ("seashell", 255, 245, 238),
+    ("sienna", 160, 82, 45),
+    ("silver", 192, 192, 192),
+    ("skyblue", 135, 206, 235),
+    ("slateblue", 106, 90, 205),
+    ("slategray", 112, 128, 144),
+    ("slategrey", 112, 128, 144),
+    ("snow", 255, 250, 250),
+    ("springgreen", 0, 255, 127),
+    ("steelblue", 70, 130, 180),
+    ("tan", 210, 180, 140),
+    ("teal", 0, 128, 128),
+    ("thistle", 216, 191, 216),
+    ("tomato", 255, 99, 71),
+    ("turquoise", 64, 224, 208),
+    ("violet", 238, 130, 238),
+    ("wheat", 245, 222, 179),
+    ("white", 255, 255, 255),
+    ("whitesmoke", 245, 245, 245),
+    ("yellow", 255, 255, 0),
+    ("yellowgreen", 154, 205, 50),
+];
+
 fn parse_named_color(name: &str) -> Option<Color> {
-    match name {
-        "red" => Some(Color { r: 255, g: 0, b: 0 }),
-        "green" => Some(Color { r: 0, g: 128, b: 0 }),
-        "blue" => Some(Color { r: 0, g: 0, b: 255 }),
-        "white" => Some(Color {
-            r: 255,
-            g: 255,
-            b: 255,
-        }),
-        "black" => Some(Color { r: 0, g: 0, b: 0 }),
-        "yellow" => Some(Color {
-            r: 255,
-            g: 255,
-            b: 0,
-        }),
-        "cyan" => Some(Color {
-            r: 0,
-            g: 255,
-            b: 255,
-        }),
-        "magenta" => Some(Color {
-            r: 255,
-            g: 0,
-            b: 255,
-        }),
-        "orange" => Some(Color {
-            r: 255,
-            g: 165,
-            b: 0,
-        }),
-        "purple" => Some(Color {
-            r: 128,
-            g: 0,
-            b: 128,
-        }),
-        "pink" => Some(Color {
-            r: 255,
-            g: 192,
-            b: 203,
-        }),
-        "brown" => Some(Color {
-            r: 165,
-            g: 42,
-            b: 42,
-        }),
-        "gray" | "grey" => Some(Color {
-            r: 128,
-            g: 128,
-            b: 128,
-        }),
-        "lime" => Some(Color { r: 0, g: 255, b: 0 }),
-        "navy" => Some(Color { r: 0, g: 0, b: 128 }),
-        "maroon" => Some(Color { r: 128, g: 0, b: 0 }),
-        "olive" => Some(Color {
-            r: 128,
-            g: 128,
-            b: 0,
-        }),
-        "teal" => Some(Color {
-            r: 0,
-            g: 128,
-            b: 128,
-        }),
-        "silver" => Some(Color {
-            r: 192,
-            g: 192,
-            b: 192,
-        }),
-        _ => None,
+    if name == "transparent" {
+        return Some(Color { r: 0, g: 0, b: 0, a: 0 });
     }
+
+    NAMED_COLORS
+        .iter()
+        .find(|(candidate, ..)| *candidate == name)
+        .map(|&(_, r, g, b)| Color::opaque(r, g, b))
+}
+
+/// Finds the closest CSS named color by squared Euclidean distance in RGB
+/// space, used to give every result a human-friendly label even when the
+/// input wasn't a name to begin with.
+fn nearest_named_color(color: &Color) -> &'static str {
+    NAMED_COLORS
+        .iter()
+        .min_by_key(|(_, r, g, b)| {
+            let dr = *r as i32 - color.r as i32;
+            let dg = *g as i32 - color.g as i32;
+            let db = *b as i32 - color.b as i32;
+            dr * dr + dg * dg + db * db
+        })
+        .map(|&(name, ..)| name)
+        .unwrap_or("black")
 }
 
 fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (u16, u8, u8) {
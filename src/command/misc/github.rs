@@ -1,6 +1,15 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
-use crate::{Context, Error, util::command::{check_cooldown, create_success_response}};
+use crate::{
+    Context, Error,
+    error::BotError,
+    util::{
+        command::{check_cooldown, create_error_response, create_success_response},
+        github_client::GitHubClient,
+    },
+};
 
 #[derive(Deserialize)]
 struct GitHubUser {
@@ -50,6 +59,19 @@ struct GitHubLicense {
     spdx_id: Option<String>,
 }
 
+#[derive(Deserialize)]
+struct GitHubRelease {
+    tag_name: String,
+    name: Option<String>,
+    published_at: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GitHubContributor {
+    login: String,
+    contributions: u32,
+}
+
 #[poise::command(
     slash_command,
     description_localized("en-US", "Get GitHub user or repository information")
@@ -57,26 +79,55 @@ struct GitHubLicense {
 pub async fn github(
     ctx: Context<'_>,
     #[description = "GitHub username, repository, or URL"] input: String,
+    #[description = "Show releases, language breakdown, and top contributors for a repository"]
+    detail: Option<bool>,
 ) -> Result<(), Error> {
     check_cooldown(&ctx, "github", ctx.data().config.cooldowns.github_cooldown).await?;
 
     let input = input.trim();
     let (user, repo) = parse_github_input(input);
+    let github_client = &ctx.data().github_client;
 
     if let Some(repo_name) = repo {
-        let (title, content) = get_repository_info(&user, &repo_name).await?;
-        let embed = create_success_response(&title, &content, false, &ctx.data().config);
-        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        match get_repository_info(github_client, &user, &repo_name, detail.unwrap_or(false)).await {
+            Ok((title, content)) => {
+                let embed = create_success_response(&title, &content, false, &ctx.data().config);
+                ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            }
+            Err(BotError::RateLimited { reset_at }) => {
+                ctx.send(poise::CreateReply::default().embed(rate_limited_response(reset_at)))
+                    .await?;
+            }
+            Err(e) => return Err(e),
+        }
     } else {
-        let (title, content, avatar_url) = get_user_info(&user).await?;
-        let mut embed = create_success_response(&title, &content, false, &ctx.data().config);
-        embed = embed.thumbnail(avatar_url);
-        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        match get_user_info(github_client, &user).await {
+            Ok((title, content, avatar_url)) => {
+                let mut embed = create_success_response(&title, &content, false, &ctx.data().config);
+                embed = embed.thumbnail(avatar_url);
+                ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            }
+            Err(BotError::RateLimited { reset_at }) => {
+                ctx.send(poise::CreateReply::default().embed(rate_limited_response(reset_at)))
+                    .await?;
+            }
+            Err(e) => return Err(e),
+        }
     }
-    
+
     Ok(())
 }
 
+fn rate_limited_response(reset_at: chrono::DateTime<chrono::Utc>) -> poise::serenity_prelude::CreateEmbed {
+    create_error_response(
+        "GitHub Rate Limited",
+        &format!(
+            "The GitHub API rate limit has been exhausted. Try again at {}.",
+            reset_at.format("%H:%M UTC")
+        ),
+    )
+}
+
 fn parse_github_input(input: &str) -> (String, Option<String>) {
     let input = input
         .strip_prefix("https://github.com/")
@@ -94,25 +145,23 @@ fn parse_github_input(input: &str) -> (String, Option<String>) {
     }
 }
 
-async fn get_user_info(username: &str) -> Result<(String, String, String), Error> {
-    let client = reqwest::Client::new();
+async fn get_user_info(
+    github_client: &GitHubClient,
+    username: &str,
+) -> Result<(String, String, String), Error> {
     let url = format!("https://api.github.com/users/{}", username);
 
-    let response = client
-        .get(&url)
-        .header("User-Agent", "Arisa-Bot/1.0")
-        .send()
-        .await?;
-
-    if !response.status().is_success() {
-        return Ok((
-            "User not found".to_string(),
-            format!("Could not find GitHub user: {}", username),
-            String::new(),
-        ));
-    }
-
-    let user: GitHubUser = response.json().await?;
+    let user: GitHubUser = match github_client.get(&url).await {
+        Ok(user) => user,
+        Err(BotError::RateLimited { reset_at }) => return Err(BotError::RateLimited { reset_at }),
+        Err(_) => {
+            return Ok((
+                "User not found".to_string(),
+                format!("Could not find GitHub user: {}", username),
+                String::new(),
+            ));
+        }
+    };
 
     let mut description = format!(
         "**Username:** {}\n**Public Repos:** {}\n**Followers:** {} | **Following:** {}\n**Public \
@@ -152,24 +201,24 @@ async fn get_user_info(username: &str) -> Result<(String, String, String), Error
     Ok((title, description, user.avatar_url))
 }
 
-async fn get_repository_info(username: &str, repo_name: &str) -> Result<(String, String), Error> {
-    let client = reqwest::Client::new();
+async fn get_repository_info(
+    github_client: &GitHubClient,
+    username: &str,
+    repo_name: &str,
+    detailed: bool,
+) -> Result<(String, String), Error> {
     let url = format!("https://api.github.com/repos/{}/{}", username, repo_name);
 
-    let response = client
-        .get(&url)
-        .header("User-Agent", "Arisa-Bot/1.0")
-        .send()
-        .await?;
-
-    if !response.status().is_success() {
-        return Ok((
-            "Repository not found".to_string(),
-            format!("Could not find repository: {}/{}", username, repo_name),
-        ));
-    }
-
-    let repo: GitHubRepo = response.json().await?;
+    let repo: GitHubRepo = match github_client.get(&url).await {
+        Ok(repo) => repo,
+        Err(BotError::RateLimited { reset_at }) => return Err(BotError::RateLimited { reset_at }),
+        Err(_) => {
+            return Ok((
+                "Repository not found".to_string(),
+                format!("Could not find repository: {}/{}", username, repo_name),
+            ));
+        }
+    };
 
     let mut description = format!(
         "**Owner:** {}\n**Stars:** {} | **Forks:** {} | **Watchers:** {}\n**Open Issues:** \
@@ -218,6 +267,10 @@ async fn get_repository_info(username: &str, repo_name: &str) -> Result<(String,
         description.push_str(&format!("\n**Status:** {}", status_flags.join(" | ")));
     }
 
+    if detailed {
+        description.push_str(&fetch_repository_detail(github_client, username, repo_name).await);
+    }
+
     if let Ok(created) = chrono::DateTime::parse_from_rfc3339(&repo.created_at) {
         description.push_str(&format!("\n**Created:** {}", created.format("%B %d, %Y")));
     }
@@ -233,4 +286,75 @@ async fn get_repository_info(username: &str, repo_name: &str) -> Result<(String,
 
     let title = format!("Repository: {}", repo.full_name);
     Ok((title, description))
+}
+
+/// Fetches the latest release, language breakdown, and top contributors
+/// concurrently. Each call tolerates its own failure (e.g. a repo with no
+/// releases 404s) by contributing nothing to the returned text rather than
+/// failing the whole command.
+async fn fetch_repository_detail(github_client: &GitHubClient, username: &str, repo_name: &str) -> String {
+    let release_url = format!(
+        "https://api.github.com/repos/{}/{}/releases/latest",
+        username, repo_name
+    );
+    let languages_url = format!(
+        "https://api.github.com/repos/{}/{}/languages",
+        username, repo_name
+    );
+    let contributors_url = format!(
+        "https://api.github.com/repos/{}/{}/contributors?per_page=5",
+        username, repo_name
+    );
+
+    let (release, languages, contributors) = tokio::join!(
+        github_client.get::<GitHubRelease>(&release_url),
+        github_client.get::<HashMap<String, u64>>(&languages_url),
+        github_client.get::<Vec<GitHubContributor>>(&contributors_url),
+    );
+
+    let mut detail = String::new();
+
+    if let Ok(release) = release {
+        let mut line = format!("**Tag:** {}", release.tag_name);
+        if let Some(name) = &release.name {
+            if !name.is_empty() {
+                line.push_str(&format!(" ({})", name));
+            }
+        }
+        if let Some(published_at) = &release.published_at {
+            if let Ok(date) = chrono::DateTime::parse_from_rfc3339(published_at) {
+                line.push_str(&format!(" — {}", date.format("%B %d, %Y")));
+            }
+        }
+        detail.push_str(&format!("\n\n**Latest Release:** {}", line));
+    }
+
+    if let Ok(languages) = languages {
+        let total: u64 = languages.values().sum();
+        if total > 0 {
+            let mut breakdown: Vec<(String, u64)> = languages.into_iter().collect();
+            breakdown.sort_by(|a, b| b.1.cmp(&a.1));
+
+            let lines: Vec<String> = breakdown
+                .iter()
+                .take(5)
+                .map(|(name, bytes)| format!("{} {:.1}%", name, (*bytes as f64 / total as f64) * 100.0))
+                .collect();
+
+            detail.push_str(&format!("\n\n**Languages:** {}", lines.join(", ")));
+        }
+    }
+
+    if let Ok(contributors) = contributors {
+        if !contributors.is_empty() {
+            let lines: Vec<String> = contributors
+                .iter()
+                .take(5)
+                .map(|c| format!("{} ({})", c.login, c.contributions))
+                .collect();
+            detail.push_str(&format!("\n\n**Top Contributors:** {}", lines.join(", ")));
+        }
+    }
+
+    detail
 }
\ No newline at end of file
@@ -5,6 +5,11 @@ use crate::{
     util::embed::{CatppuccinColors, create_info_embed},
 };
 
+/// A near-miss is only auto-resolved when it's the single closest command;
+/// beyond this distance it's not worth suggesting at all.
+const MAX_SUGGESTION_DISTANCE: usize = 3;
+const MAX_SUGGESTIONS: usize = 3;
+
 #[poise::command(
     slash_command,
     description_localized("en-US", "Show help information about commands")
@@ -17,13 +22,46 @@ pub async fn help(
 ) -> Result<(), Error> {
     if let Some(command_name) = command {
         let commands = &ctx.framework().options().commands;
-        if let Some(cmd) = commands.iter().find(|c| c.name == command_name) {
+
+        let mut by_distance: Vec<(&poise::Command<crate::Data, Error>, usize)> = commands
+            .iter()
+            .map(|c| (c, levenshtein::levenshtein(&command_name, &c.name)))
+            .collect();
+        by_distance.sort_by_key(|(_, distance)| *distance);
+
+        let closest = by_distance.first();
+        let unambiguous = closest.is_some_and(|(_, distance)| {
+            by_distance.iter().filter(|(_, d)| d == distance).count() == 1
+        });
+
+        let resolved = closest.filter(|(_, distance)| {
+            *distance == 0 || (*distance <= MAX_SUGGESTION_DISTANCE && unambiguous)
+        });
+
+        if let Some((cmd, _)) = resolved {
             let embed = create_command_help_embed(cmd, &ctx.data().config);
             ctx.send(poise::CreateReply::default().embed(embed).ephemeral(true))
                 .await?;
         } else {
+            let suggestions: Vec<&str> = by_distance
+                .iter()
+                .filter(|(_, distance)| *distance <= MAX_SUGGESTION_DISTANCE)
+                .take(MAX_SUGGESTIONS)
+                .map(|(cmd, _)| cmd.name.as_str())
+                .collect();
+
+            let mut description = format!("No command named `{}` was found.", command_name);
+            if !suggestions.is_empty() {
+                let hints = suggestions
+                    .iter()
+                    .map(|name| format!("`/{}`", name))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                description.push_str(&format!("\n\nDid you mean: {}", hints));
+            }
+
             let embed = create_info_embed("Command Not Found", &ctx.data().config)
-                .description(format!("No command named `{}` was found.", command_name))
+                .description(description)
                 .color(CatppuccinColors::RED);
             ctx.send(poise::CreateReply::default().embed(embed).ephemeral(true))
                 .await?;
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use crate::{
+    Context, Error,
+    util::{
+        command::{
+            check_cooldown, create_error_response, create_success_response, validate_input_size,
+        },
+        js_sandbox::{self, OptLevel},
+    },
+};
+
+#[poise::command(
+    slash_command,
+    description_localized(
+        "en-US",
+        "Run a JavaScript snippet in a sandboxed interpreter"
+    )
+)]
+pub async fn js(
+    ctx: Context<'_>,
+    #[description = "JavaScript source to run"] code: String,
+    #[description = "Compiler optimization level (defaults to Basic)"] opt_level: Option<OptLevel>,
+    #[description = "Show the engine's lowered IR instead of running the program"] emit_llir: Option<
+        bool,
+    >,
+) -> Result<(), Error> {
+    check_cooldown(&ctx, "js", ctx.data().config.cooldowns.hash_cooldown).await?;
+
+    if let Err(e) = validate_input_size(&code, &ctx.data().config) {
+        let embed = create_error_response("JavaScript Error", &e.to_string());
+        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        return Ok(());
+    }
+
+    ctx.defer().await?;
+
+    let opt_level = opt_level.unwrap_or(OptLevel::Basic);
+    let timeout = Duration::from_secs(ctx.data().config.js.timeout_seconds);
+
+    if emit_llir.unwrap_or(false) {
+        let source = code.clone();
+        let llir = tokio::task::spawn_blocking(move || js_sandbox::emit_llir(&source, opt_level))
+            .await
+            .map_err(|e| crate::error::BotError::InvalidFormat(format!("Compile task panicked: {}", e)))?;
+
+        return match llir {
+            Ok(llir) => {
+                let embed = create_success_response("JS LLIR", &llir, true, &ctx.data().config);
+                ctx.send(poise::CreateReply::default().embed(embed)).await?;
+                Ok(())
+            }
+            Err(e) => {
+                let embed = create_error_response("JavaScript Error", &e.to_string());
+                ctx.send(poise::CreateReply::default().embed(embed)).await?;
+                Ok(())
+            }
+        };
+    }
+
+    let source = code.clone();
+    let run = tokio::time::timeout(
+        timeout,
+        tokio::task::spawn_blocking(move || js_sandbox::run_script(&source, opt_level)),
+    )
+    .await;
+
+    let output = match run {
+        Ok(join_result) => join_result
+            .map_err(|e| crate::error::BotError::InvalidFormat(format!("Script task panicked: {}", e)))?,
+        Err(_) => {
+            let embed = create_error_response(
+                "JavaScript Error",
+                &format!(
+                    "Script timed out after {} second(s).",
+                    ctx.data().config.js.timeout_seconds
+                ),
+            );
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            return Ok(());
+        }
+    };
+
+    match output {
+        Ok(output) => {
+            let mut content = output.console_log;
+            if let Some(result) = output.result {
+                if result != "undefined" {
+                    content.push_str(&format!("\n=> {}", result));
+                }
+            }
+            if content.trim().is_empty() {
+                content = "(no output)".to_string();
+            }
+
+            let embed = create_success_response("JS Output", &content, true, &ctx.data().config);
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        }
+        Err(e) => {
+            let embed = create_error_response("JavaScript Error", &e.to_string());
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        }
+    }
+
+    Ok(())
+}
@@ -2,8 +2,10 @@ pub mod color;
 pub mod github;
 pub mod hawk_tuah;
 pub mod help;
+pub mod js;
 
 pub use color::color;
 pub use github::github;
 pub use hawk_tuah::hawktuah;
-pub use help::help;
\ No newline at end of file
+pub use help::help;
+pub use js::js;
\ No newline at end of file
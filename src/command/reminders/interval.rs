@@ -0,0 +1,85 @@
+use chrono::Utc;
+
+use crate::{
+    Context, Error,
+    command::encoding::timestamp::parse_date_string,
+    util::command::{check_cooldown, create_error_response, create_success_response},
+};
+
+use super::{rrule::RRule, store};
+
+#[poise::command(
+    slash_command,
+    description_localized("en-US", "Schedule a recurring reminder from an RRULE")
+)]
+pub async fn interval(
+    ctx: Context<'_>,
+    #[description = "RRULE, e.g. FREQ=WEEKLY;INTERVAL=2;BYDAY=MO;UNTIL=20251231"] rrule: String,
+    #[description = "What to remind you about"] message: String,
+    #[description = "First occurrence to start counting from (defaults to now)"] dtstart: Option<String>,
+) -> Result<(), Error> {
+    check_cooldown(
+        &ctx,
+        "interval",
+        ctx.data().config.cooldowns.per_user_cooldown,
+    )
+    .await?;
+
+    let parsed_rule = match RRule::parse(&rrule) {
+        Ok(rule) => rule,
+        Err(e) => {
+            let embed = create_error_response("Invalid RRULE", &e);
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            return Ok(());
+        }
+    };
+
+    let dtstart = match dtstart {
+        Some(raw) => match parse_date_string(&raw) {
+            Ok(dt) => dt,
+            Err(e) => {
+                let embed = create_error_response(
+                    "Invalid Start Time",
+                    &format!("Could not parse '{}': {}", raw, e),
+                );
+                ctx.send(poise::CreateReply::default().embed(embed)).await?;
+                return Ok(());
+            }
+        },
+        None => Utc::now(),
+    };
+
+    let next_run = match parsed_rule.iter_from(dtstart).next() {
+        Some(next) => next,
+        None => {
+            let embed = create_error_response(
+                "Invalid RRULE",
+                "This rule never produces an occurrence after the start time.",
+            );
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            return Ok(());
+        }
+    };
+
+    let reminder = store::add_reminder(
+        ctx.author().id.get(),
+        ctx.channel_id().get(),
+        message.clone(),
+        dtstart,
+        Some(rrule.clone()),
+        next_run,
+    )
+    .await;
+
+    let content = format!(
+        "**Reminder #{}** recurring with `{}`\n**Next occurrence:** {}\n**Message:** {}",
+        reminder.id,
+        rrule,
+        next_run.format("%Y-%m-%d %H:%M:%S UTC"),
+        message
+    );
+
+    let embed = create_success_response("Recurring Reminder Scheduled", &content, false, &ctx.data().config);
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
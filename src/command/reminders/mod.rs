@@ -0,0 +1,9 @@
+pub mod interval;
+pub mod remind;
+pub mod rrule;
+pub mod scheduler;
+mod store;
+
+pub use interval::interval;
+pub use remind::remind;
+pub use scheduler::start_reminder_scheduler;
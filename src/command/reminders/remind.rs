@@ -0,0 +1,65 @@
+use chrono::Utc;
+
+use crate::{
+    Context, Error,
+    command::encoding::timestamp::parse_date_string,
+    util::command::{check_cooldown, create_error_response, create_success_response},
+};
+
+use super::store;
+
+#[poise::command(
+    slash_command,
+    description_localized("en-US", "Schedule a one-off reminder")
+)]
+pub async fn remind(
+    ctx: Context<'_>,
+    #[description = "When to remind you (e.g. 2024-12-25 09:00:00)"] when: String,
+    #[description = "What to remind you about"] message: String,
+) -> Result<(), Error> {
+    check_cooldown(
+        &ctx,
+        "remind",
+        ctx.data().config.cooldowns.per_user_cooldown,
+    )
+    .await?;
+
+    let due_at = match parse_date_string(&when) {
+        Ok(dt) => dt,
+        Err(e) => {
+            let embed = create_error_response(
+                "Invalid Time",
+                &format!("Could not parse '{}': {}", when, e),
+            );
+            ctx.send(poise::CreateReply::default().embed(embed)).await?;
+            return Ok(());
+        }
+    };
+
+    if due_at <= Utc::now() {
+        let embed = create_error_response("Invalid Time", "That time is already in the past.");
+        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        return Ok(());
+    }
+
+    let reminder = store::add_reminder(
+        ctx.author().id.get(),
+        ctx.channel_id().get(),
+        message.clone(),
+        due_at,
+        None,
+        due_at,
+    )
+    .await;
+
+    let content = format!(
+        "**Reminder #{}** set for {}\n**Message:** {}",
+        reminder.id,
+        due_at.format("%Y-%m-%d %H:%M:%S UTC"),
+        message
+    );
+
+    let embed = create_success_response("Reminder Scheduled", &content, false, &ctx.data().config);
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
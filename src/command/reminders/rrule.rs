@@ -0,0 +1,400 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Timelike, Utc, Weekday};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Freq {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, Clone)]
+pub struct RRule {
+    pub freq: Freq,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<DateTime<Utc>>,
+    pub by_day: Vec<Weekday>,
+    pub by_month_day: Vec<i32>,
+    pub by_month: Vec<u32>,
+}
+
+impl RRule {
+    /// Parse an iCalendar-style RRULE value (everything after `RRULE:`), e.g.
+    /// `FREQ=WEEKLY;INTERVAL=2;BYDAY=MO;UNTIL=20251231`.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut freq = None;
+        let mut interval = 1u32;
+        let mut count = None;
+        let mut until = None;
+        let mut by_day = Vec::new();
+        let mut by_month_day = Vec::new();
+        let mut by_month = Vec::new();
+
+        for part in input.trim().trim_start_matches("RRULE:").split(';') {
+            let part = part.trim();
+            if part.is_empty() {
+                continue;
+            }
+
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("malformed RRULE part '{}'", part))?;
+
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    freq = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Freq::Daily,
+                        "WEEKLY" => Freq::Weekly,
+                        "MONTHLY" => Freq::Monthly,
+                        "YEARLY" => Freq::Yearly,
+                        other => return Err(format!("unsupported FREQ '{}'", other)),
+                    });
+                }
+                "INTERVAL" => {
+                    interval = value
+                        .parse()
+                        .map_err(|_| format!("invalid INTERVAL '{}'", value))?;
+                }
+                "COUNT" => {
+                    count = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("invalid COUNT '{}'", value))?,
+                    );
+                }
+                "UNTIL" => {
+                    until = Some(parse_until(value)?);
+                }
+                "BYDAY" => {
+                    for token in value.split(',') {
+                        by_day.push(parse_weekday(token)?);
+                    }
+                }
+                "BYMONTHDAY" => {
+                    for token in value.split(',') {
+                        by_month_day.push(
+                            token
+                                .parse::<i32>()
+                                .map_err(|_| format!("invalid BYMONTHDAY '{}'", token))?,
+                        );
+                    }
+                }
+                "BYMONTH" => {
+                    for token in value.split(',') {
+                        by_month.push(
+                            token
+                                .parse::<u32>()
+                                .map_err(|_| format!("invalid BYMONTH '{}'", token))?,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(RRule {
+            freq: freq.ok_or_else(|| "RRULE is missing FREQ".to_string())?,
+            interval: interval.max(1),
+            count,
+            until,
+            by_day,
+            by_month_day,
+            by_month,
+        })
+    }
+
+    /// Iterate occurrences starting at `dtstart`, inclusive.
+    pub fn iter_from(&self, dtstart: DateTime<Utc>) -> RRuleIter {
+        RRuleIter::new(self.clone(), dtstart, dtstart - Duration::nanoseconds(1), 0)
+    }
+
+    /// Resume iteration after an instant that has already been fired, having
+    /// already produced `produced` occurrences against `COUNT`.
+    pub fn resume(&self, dtstart: DateTime<Utc>, after: DateTime<Utc>, produced: u32) -> RRuleIter {
+        RRuleIter::new(self.clone(), dtstart, after, produced)
+    }
+}
+
+fn parse_weekday(token: &str) -> Result<Weekday, String> {
+    // Leading ordinal prefixes (e.g. `2MO`) are not meaningful outside BYSETPOS
+    // expansion, so only the trailing two-letter code is significant here.
+    let code = &token[token.len().saturating_sub(2)..];
+    match code.to_ascii_uppercase().as_str() {
+        "MO" => Ok(Weekday::Mon),
+        "TU" => Ok(Weekday::Tue),
+        "WE" => Ok(Weekday::Wed),
+        "TH" => Ok(Weekday::Thu),
+        "FR" => Ok(Weekday::Fri),
+        "SA" => Ok(Weekday::Sat),
+        "SU" => Ok(Weekday::Sun),
+        other => Err(format!("invalid BYDAY weekday '{}'", other)),
+    }
+}
+
+fn parse_until(value: &str) -> Result<DateTime<Utc>, String> {
+    if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ") {
+        return Ok(Utc.from_utc_datetime(&dt));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y%m%d") {
+        return Ok(Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap()));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap()));
+    }
+    Err(format!("invalid UNTIL value '{}'", value))
+}
+
+/// Lazily produces occurrences for an [`RRule`] in chronological order.
+pub struct RRuleIter {
+    rule: RRule,
+    dtstart: DateTime<Utc>,
+    /// Exclusive lower bound: only candidates strictly after this are yielded.
+    floor: DateTime<Utc>,
+    period_start: DateTime<Utc>,
+    produced: u32,
+    pending: std::collections::VecDeque<DateTime<Utc>>,
+    finished: bool,
+}
+
+const MAX_EMPTY_PERIODS: u32 = 10_000;
+
+impl RRuleIter {
+    fn new(
+        rule: RRule,
+        dtstart: DateTime<Utc>,
+        floor: DateTime<Utc>,
+        produced: u32,
+    ) -> Self {
+        let period_start = if floor > dtstart { floor } else { dtstart };
+        Self {
+            rule,
+            dtstart,
+            floor,
+            period_start,
+            produced,
+            pending: std::collections::VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    fn advance_period(&self) -> DateTime<Utc> {
+        let step = self.rule.interval.max(1) as i32;
+        match self.rule.freq {
+            Freq::Daily => self.period_start + Duration::days(step as i64),
+            Freq::Weekly => self.period_start + Duration::weeks(step as i64),
+            Freq::Monthly => add_months(self.period_start, step),
+            Freq::Yearly => add_months(self.period_start, step * 12),
+        }
+    }
+
+    fn expand_period(&self) -> Vec<DateTime<Utc>> {
+        let anchor = self.period_start;
+
+        if !self.rule.by_month.is_empty() && !self.rule.by_month.contains(&anchor.month()) {
+            return Vec::new();
+        }
+
+        match self.rule.freq {
+            Freq::Daily => vec![anchor],
+            Freq::Weekly => {
+                if self.rule.by_day.is_empty() {
+                    vec![anchor]
+                } else {
+                    expand_week(anchor, &self.rule.by_day)
+                }
+            }
+            Freq::Monthly => expand_month(anchor, self.dtstart, &self.rule.by_month_day, &self.rule.by_day),
+            Freq::Yearly => expand_year(anchor, self.dtstart, &self.rule.by_month, &self.rule.by_month_day),
+        }
+    }
+}
+
+impl Iterator for RRuleIter {
+    type Item = DateTime<Utc>;
+
+    fn next(&mut self) -> Option<DateTime<Utc>> {
+        if self.finished {
+            return None;
+        }
+
+        if let Some(count) = self.rule.count {
+            if self.produced >= count {
+                self.finished = true;
+                return None;
+            }
+        }
+
+        let mut empty_periods = 0;
+        loop {
+            if let Some(candidate) = self.pending.pop_front() {
+                if candidate <= self.floor {
+                    continue;
+                }
+
+                if let Some(until) = self.rule.until {
+                    if candidate > until {
+                        self.finished = true;
+                        return None;
+                    }
+                }
+
+                self.produced += 1;
+                self.floor = candidate;
+                return Some(candidate);
+            }
+
+            let mut candidates = self.expand_period();
+            candidates.sort();
+            self.pending = candidates.into_iter().collect();
+            self.period_start = self.advance_period();
+
+            if self.pending.is_empty() {
+                empty_periods += 1;
+                if empty_periods > MAX_EMPTY_PERIODS {
+                    self.finished = true;
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+fn expand_week(anchor: DateTime<Utc>, by_day: &[Weekday]) -> Vec<DateTime<Utc>> {
+    let week_start = anchor - Duration::days(anchor.weekday().num_days_from_monday() as i64);
+
+    by_day
+        .iter()
+        .map(|wd| week_start + Duration::days(wd.num_days_from_monday() as i64))
+        .collect()
+}
+
+fn expand_month(
+    anchor: DateTime<Utc>,
+    dtstart: DateTime<Utc>,
+    by_month_day: &[i32],
+    by_day: &[Weekday],
+) -> Vec<DateTime<Utc>> {
+    let year = anchor.year();
+    let month = anchor.month();
+
+    if !by_month_day.is_empty() {
+        return by_month_day
+            .iter()
+            .filter_map(|&day| day_in_month(year, month, day, &anchor, &dtstart))
+            .collect();
+    }
+
+    if !by_day.is_empty() {
+        return all_weekdays_in_month(year, month, by_day, &anchor);
+    }
+
+    // No BYxxx refinement: recur on DTSTART's own day-of-month, skipping
+    // months that don't have it rather than rolling over.
+    day_in_month(year, month, dtstart.day() as i32, &anchor, &dtstart)
+        .into_iter()
+        .collect()
+}
+
+fn expand_year(
+    anchor: DateTime<Utc>,
+    dtstart: DateTime<Utc>,
+    by_month: &[u32],
+    by_month_day: &[i32],
+) -> Vec<DateTime<Utc>> {
+    let year = anchor.year();
+    let months: Vec<u32> = if by_month.is_empty() {
+        vec![dtstart.month()]
+    } else {
+        by_month.clone().to_vec()
+    };
+
+    let mut out = Vec::new();
+    for month in months {
+        if !by_month_day.is_empty() {
+            for &day in by_month_day {
+                if let Some(dt) = day_in_month(year, month, day, &anchor, &dtstart) {
+                    out.push(dt);
+                }
+            }
+        } else if let Some(dt) = day_in_month(year, month, dtstart.day() as i32, &anchor, &dtstart) {
+            out.push(dt);
+        }
+    }
+    out
+}
+
+fn day_in_month(
+    year: i32,
+    month: u32,
+    day: i32,
+    time_source: &DateTime<Utc>,
+    dtstart: &DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let day = if day > 0 {
+        day as u32
+    } else {
+        // Negative BYMONTHDAY counts back from the end of the month.
+        let last = last_day_of_month(year, month);
+        (last as i32 + day + 1).max(0) as u32
+    };
+
+    let date = NaiveDate::from_ymd_opt(year, month, day)?;
+    let _ = time_source;
+    let naive_dt = date.and_hms_opt(
+        dtstart.hour(),
+        dtstart.minute(),
+        dtstart.second(),
+    )?;
+    Some(Utc.from_utc_datetime(&naive_dt))
+}
+
+fn all_weekdays_in_month(
+    year: i32,
+    month: u32,
+    by_day: &[Weekday],
+    time_source: &DateTime<Utc>,
+) -> Vec<DateTime<Utc>> {
+    let mut out = Vec::new();
+    let mut day = 1;
+    while let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
+        if by_day.contains(&date.weekday()) {
+            if let Some(naive_dt) = date.and_hms_opt(
+                time_source.hour(),
+                time_source.minute(),
+                time_source.second(),
+            ) {
+                out.push(Utc.from_utc_datetime(&naive_dt));
+            }
+        }
+        day += 1;
+    }
+    out
+}
+
+fn last_day_of_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .unwrap()
+        .pred_opt()
+        .unwrap()
+        .day()
+}
+
+fn add_months(dt: DateTime<Utc>, months: i32) -> DateTime<Utc> {
+    let total = dt.year() * 12 + (dt.month() as i32 - 1) + months;
+    let year = total.div_euclid(12);
+    let month = (total.rem_euclid(12) + 1) as u32;
+    let day = dt.day().min(last_day_of_month(year, month));
+
+    let naive_dt = NaiveDate::from_ymd_opt(year, month, day)
+        .unwrap()
+        .and_hms_opt(dt.hour(), dt.minute(), dt.second())
+        .unwrap();
+    Utc.from_utc_datetime(&naive_dt)
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use poise::serenity_prelude::{ChannelId, Context as SerenityContext, Mentionable, UserId};
+use tokio::time::Duration;
+
+use super::store;
+
+/// Wakes at the nearest due reminder, fires it, then re-computes the next
+/// occurrence (if any) and goes back to sleep. Mirrors the min-heap/next-run
+/// pattern: rather than polling on a fixed tick, we sleep exactly until the
+/// soonest `next_run` in the store.
+pub fn start_reminder_scheduler(ctx: Arc<SerenityContext>) {
+    tokio::spawn(async move {
+        loop {
+            let wait = match store::next_due().await {
+                Some(next_run) => {
+                    let now = Utc::now();
+                    (next_run - now).to_std().unwrap_or(Duration::from_secs(0))
+                }
+                None => Duration::from_secs(60),
+            };
+
+            tokio::time::sleep(wait).await;
+            fire_due_reminders(&ctx).await;
+        }
+    });
+}
+
+async fn fire_due_reminders(ctx: &SerenityContext) {
+    let now = Utc::now();
+    let due = store::take_due(now).await;
+
+    for reminder in due {
+        let channel = ChannelId::new(reminder.channel_id);
+        let content = format!(
+            "{} ⏰ {}",
+            UserId::new(reminder.user_id).mention(),
+            reminder.message
+        );
+
+        if let Err(e) = channel.say(&ctx.http, content).await {
+            println!("Error sending reminder {}: {:?}", reminder.id, e);
+        }
+    }
+
+    store::reschedule_fired(now).await;
+}
@@ -0,0 +1,152 @@
+use std::sync::{Arc, atomic::{AtomicU64, Ordering}};
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+const REMINDER_FILE: &str = "reminders.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reminder {
+    pub id: u64,
+    pub user_id: u64,
+    pub channel_id: u64,
+    pub message: String,
+    pub dtstart: DateTime<Utc>,
+    pub rrule: Option<String>,
+    pub next_run: DateTime<Utc>,
+    pub occurrences_fired: u32,
+}
+
+static REMINDER_STORE: Lazy<Arc<RwLock<Vec<Reminder>>>> =
+    Lazy::new(|| Arc::new(RwLock::new(load_reminders())));
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+fn load_reminders() -> Vec<Reminder> {
+    let reminders: Vec<Reminder> = std::fs::read_to_string(REMINDER_FILE)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+
+    if let Some(max_id) = reminders.iter().map(|r| r.id).max() {
+        NEXT_ID.store(max_id + 1, Ordering::SeqCst);
+    }
+
+    reminders
+}
+
+async fn persist(reminders: &[Reminder]) {
+    if let Ok(content) = serde_json::to_string_pretty(reminders) {
+        if let Err(e) = tokio::fs::write(REMINDER_FILE, content).await {
+            println!("Error saving reminders: {:?}", e);
+        }
+    }
+}
+
+pub async fn add_reminder(
+    user_id: u64,
+    channel_id: u64,
+    message: String,
+    dtstart: DateTime<Utc>,
+    rrule: Option<String>,
+    next_run: DateTime<Utc>,
+) -> Reminder {
+    let reminder = Reminder {
+        id: NEXT_ID.fetch_add(1, Ordering::SeqCst),
+        user_id,
+        channel_id,
+        message,
+        dtstart,
+        rrule,
+        next_run,
+        occurrences_fired: 0,
+    };
+
+    let mut reminders = REMINDER_STORE.write().await;
+    reminders.push(reminder.clone());
+    persist(&reminders).await;
+
+    reminder
+}
+
+pub async fn list_reminders(user_id: u64) -> Vec<Reminder> {
+    REMINDER_STORE
+        .read()
+        .await
+        .iter()
+        .filter(|r| r.user_id == user_id)
+        .cloned()
+        .collect()
+}
+
+pub async fn cancel_reminder(user_id: u64, id: u64) -> bool {
+    let mut reminders = REMINDER_STORE.write().await;
+    let before = reminders.len();
+    reminders.retain(|r| !(r.id == id && r.user_id == user_id));
+    let removed = reminders.len() != before;
+
+    if removed {
+        persist(&reminders).await;
+    }
+
+    removed
+}
+
+pub async fn next_due() -> Option<DateTime<Utc>> {
+    REMINDER_STORE
+        .read()
+        .await
+        .iter()
+        .map(|r| r.next_run)
+        .min()
+}
+
+pub async fn take_due(now: DateTime<Utc>) -> Vec<Reminder> {
+    REMINDER_STORE
+        .read()
+        .await
+        .iter()
+        .filter(|r| r.next_run <= now)
+        .cloned()
+        .collect()
+}
+
+/// Advances each fired reminder to its next occurrence (dropping one-off
+/// reminders and exhausted recurring ones), persisting the result.
+pub async fn reschedule_fired(now: DateTime<Utc>) {
+    let mut reminders = REMINDER_STORE.write().await;
+    let mut changed = false;
+
+    reminders.retain_mut(|reminder| {
+        if reminder.next_run > now {
+            return true;
+        }
+        changed = true;
+
+        let Some(rrule_str) = &reminder.rrule else {
+            return false;
+        };
+
+        match super::rrule::RRule::parse(rrule_str) {
+            Ok(rule) => {
+                reminder.occurrences_fired += 1;
+                let mut iter =
+                    rule.resume(reminder.dtstart, reminder.next_run, reminder.occurrences_fired);
+                match iter.next() {
+                    Some(next) => {
+                        reminder.next_run = next;
+                        true
+                    }
+                    None => false,
+                }
+            }
+            Err(_) => false,
+        }
+    });
+
+    if changed {
+        persist(&reminders).await;
+    }
+}
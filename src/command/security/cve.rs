@@ -9,7 +9,10 @@ use tokio::sync::RwLock;
 
 use crate::{
     Context, Error,
-    util::command::{check_cooldown, create_success_response},
+    util::{
+        command::{check_cooldown, create_success_response},
+        db::{self, PgPool},
+    },
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -137,6 +140,10 @@ lazy_static::lazy_static! {
 
 const CACHE_DURATION: Duration = Duration::from_secs(3600);
 
+/// Caps both the batch-lookup and keyword-search result listings so a
+/// single response can't blow past Discord's embed size limit.
+const PER_PAGE_LIMIT: usize = 10;
+
 #[poise::command(
     slash_command,
     description_localized(
@@ -146,32 +153,188 @@ const CACHE_DURATION: Duration = Duration::from_secs(3600);
 )]
 pub async fn cve(
     ctx: Context<'_>,
-    #[description = "CVE ID (e.g., CVE-2019-16863)"] cve_id: String,
+    #[description = "CVE ID (e.g., CVE-2019-16863)"] cve_id: Option<String>,
+    #[description = "Comma/space-separated list of CVE IDs to look up at once"] ids: Option<String>,
+    #[description = "Search NVD by keyword instead of a specific ID"] keyword: Option<String>,
     #[description = "Show detailed technical information"] detailed: Option<bool>,
 ) -> Result<(), Error> {
     check_cooldown(&ctx, "cve", ctx.data().config.cooldowns.github_cooldown).await?;
     ctx.defer().await?;
 
-    let normalized_id = normalize_cve_id(&cve_id)?;
+    let provided = [cve_id.is_some(), ids.is_some(), keyword.is_some()]
+        .iter()
+        .filter(|p| **p)
+        .count();
 
-    let cve_data = match get_cached_cve(&normalized_id).await {
-        Some(cached) => cached,
-        None => {
-            let fetched = fetch_cve_info(&normalized_id).await?;
-            cache_cve(&normalized_id, fetched.clone()).await;
-            fetched
-        }
-    };
+    if provided == 0 {
+        return Err(crate::error::BotError::InvalidFormat(
+            "Provide a `cve_id`, a comma/space-separated `ids` list, or a `keyword`.".to_string(),
+        ));
+    }
+
+    if provided > 1 {
+        return Err(crate::error::BotError::InvalidFormat(
+            "Provide only one of `cve_id`, `ids`, or `keyword`.".to_string(),
+        ));
+    }
+
+    if let Some(cve_id) = cve_id {
+        let normalized_id = normalize_cve_id(&cve_id)?;
+        let db_pool = ctx.data().db_pool.as_ref();
+
+        let cve_data = match get_cached_cve(db_pool, &normalized_id).await {
+            Some(cached) => cached,
+            None => {
+                let fetched = fetch_cve_info(&normalized_id).await?;
+                cache_cve(db_pool, &normalized_id, fetched.clone()).await;
+                fetched
+            }
+        };
+
+        let show_detailed = detailed.unwrap_or(false);
+        let (title, description) = format_cve_response(&cve_data, show_detailed);
+
+        let embed = create_success_response(&title, &description, false, &ctx.data().config);
+        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        return Ok(());
+    }
 
-    let show_detailed = detailed.unwrap_or(false);
-    let (title, description) = format_cve_response(&cve_data, show_detailed);
+    if let Some(ids) = ids {
+        let (title, description) = batch_lookup(&ctx, &ids).await?;
+        let embed = create_success_response(&title, &description, false, &ctx.data().config);
+        ctx.send(poise::CreateReply::default().embed(embed)).await?;
+        return Ok(());
+    }
 
+    let keyword = keyword.expect("exactly one of cve_id/ids/keyword is set");
+    let (title, description) = keyword_search(&keyword).await?;
     let embed = create_success_response(&title, &description, false, &ctx.data().config);
     ctx.send(poise::CreateReply::default().embed(embed)).await?;
     Ok(())
 }
 
-fn normalize_cve_id(input: &str) -> Result<String, Error> {
+async fn batch_lookup(ctx: &Context<'_>, ids: &str) -> Result<(String, String), Error> {
+    let requested: Vec<&str> = ids
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    if requested.is_empty() {
+        return Err(crate::error::BotError::InvalidFormat(
+            "No CVE IDs found in the `ids` input.".to_string(),
+        ));
+    }
+
+    let db_pool = ctx.data().db_pool.as_ref();
+    let mut lines = Vec::new();
+
+    for raw_id in requested.into_iter().take(PER_PAGE_LIMIT) {
+        let line = match normalize_cve_id(raw_id) {
+            Ok(normalized_id) => {
+                let cve_data = match get_cached_cve(db_pool, &normalized_id).await {
+                    Some(cached) => Some(cached),
+                    None => match fetch_cve_info(&normalized_id).await {
+                        Ok(fetched) => {
+                            cache_cve(db_pool, &normalized_id, fetched.clone()).await;
+                            Some(fetched)
+                        }
+                        Err(e) => {
+                            lines.push(format!("**{}** — {}", normalized_id, e));
+                            None
+                        }
+                    },
+                };
+
+                cve_data.map(|data| format_batch_line(&data))
+            }
+            Err(e) => {
+                lines.push(format!("**{}** — {}", raw_id, e));
+                None
+            }
+        };
+
+        if let Some(line) = line {
+            lines.push(line);
+        }
+    }
+
+    Ok((
+        "CVE Batch Lookup".to_string(),
+        lines.join("\n"),
+    ))
+}
+
+fn format_batch_line(cve: &CveData) -> String {
+    let severity = cve
+        .metrics
+        .as_ref()
+        .and_then(get_best_cvss_score)
+        .map(|(score, version, severity)| format!("{} ({}) {}", score, version, severity))
+        .unwrap_or_else(|| "No CVSS score".to_string());
+
+    let published = cve
+        .published
+        .as_deref()
+        .and_then(|p| chrono::DateTime::parse_from_rfc3339(p).ok())
+        .map(|dt| dt.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    format!(
+        "**{}** — {} — published {}",
+        cve.id, severity, published
+    )
+}
+
+async fn keyword_search(keyword: &str) -> Result<(String, String), Error> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .user_agent("Arisa-Bot/1.0")
+        .build()?;
+
+    let url = format!(
+        "https://services.nvd.nist.gov/rest/json/cves/2.0?keywordSearch={}&resultsPerPage={}",
+        urlencoding::encode(keyword),
+        PER_PAGE_LIMIT
+    );
+
+    let response = client.get(&url).send().await?;
+
+    if !response.status().is_success() {
+        return Err(crate::error::BotError::GitHub(format!(
+            "NVD keyword search for '{}' failed (HTTP {})",
+            keyword,
+            response.status()
+        )));
+    }
+
+    let nvd_response: NvdResponse = response.json().await?;
+
+    if nvd_response.vulnerabilities.is_empty() {
+        return Err(crate::error::BotError::GitHub(format!(
+            "No CVEs found matching '{}'",
+            keyword
+        )));
+    }
+
+    let lines: Vec<String> = nvd_response
+        .vulnerabilities
+        .iter()
+        .take(PER_PAGE_LIMIT)
+        .map(|v| format_batch_line(&v.cve))
+        .collect();
+
+    let description = format!(
+        "Showing {} of {} results (starting at {})\n\n{}",
+        lines.len(),
+        nvd_response.total_results,
+        nvd_response.start_index,
+        lines.join("\n")
+    );
+
+    Ok((format!("CVE Search: {}", keyword), description))
+}
+
+pub(crate) fn normalize_cve_id(input: &str) -> Result<String, Error> {
     let clean = input.trim().to_uppercase();
 
     if clean.starts_with("CVE-") {
@@ -195,7 +358,11 @@ fn normalize_cve_id(input: &str) -> Result<String, Error> {
     ))
 }
 
-async fn get_cached_cve(cve_id: &str) -> Option<CveData> {
+async fn get_cached_cve(pool: Option<&PgPool>, cve_id: &str) -> Option<CveData> {
+    if let Some(pool) = pool {
+        return db::get_cached_cve(pool, cve_id, CACHE_DURATION).await;
+    }
+
     let cache = CVE_CACHE.read().await;
     if let Some(cached) = cache.get(cve_id) {
         if cached.cached_at.elapsed() < CACHE_DURATION {
@@ -205,7 +372,12 @@ async fn get_cached_cve(cve_id: &str) -> Option<CveData> {
     None
 }
 
-async fn cache_cve(cve_id: &str, data: CveData) {
+async fn cache_cve(pool: Option<&PgPool>, cve_id: &str, data: CveData) {
+    if let Some(pool) = pool {
+        db::cache_cve(pool, cve_id, &data).await;
+        return;
+    }
+
     let mut cache = CVE_CACHE.write().await;
     cache.insert(
         cve_id.to_string(),
@@ -218,7 +390,7 @@ async fn cache_cve(cve_id: &str, data: CveData) {
     cache.retain(|_, cached| cached.cached_at.elapsed() < CACHE_DURATION);
 }
 
-async fn fetch_cve_info(cve_id: &str) -> Result<CveData, Error> {
+pub(crate) async fn fetch_cve_info(cve_id: &str) -> Result<CveData, Error> {
     let client = reqwest::Client::builder()
         .timeout(Duration::from_secs(30))
         .user_agent("Arisa-Bot/1.0")
@@ -251,7 +423,7 @@ async fn fetch_cve_info(cve_id: &str) -> Result<CveData, Error> {
     Ok(nvd_response.vulnerabilities[0].cve.clone())
 }
 
-fn format_cve_response(cve: &CveData, detailed: bool) -> (String, String) {
+pub(crate) fn format_cve_response(cve: &CveData, detailed: bool) -> (String, String) {
     let mut description = String::new();
 
     if let Some(status) = &cve.vuln_status {
@@ -0,0 +1,240 @@
+use std::{collections::HashMap, sync::Arc, time::Instant};
+
+use poise::serenity_prelude::{ChannelId, Context as SerenityContext};
+use tokio::time::Duration;
+
+use crate::{
+    Context, Error,
+    config::Config,
+    error::BotError,
+    util::command::{check_cooldown, create_info_response, create_success_response},
+};
+
+use super::{fetch, store};
+
+/// How often the poller wakes up to check whether any subscription is due.
+/// Per-feed cadence is enforced against this tick via `poll_interval_minutes`,
+/// not by sleeping for the full interval, so feeds with different intervals
+/// can share one background task.
+const POLL_TICK: Duration = Duration::from_secs(60);
+
+#[poise::command(
+    slash_command,
+    description_localized("en-US", "Manage this server's RSS/Atom feed subscriptions"),
+    subcommands("feed_add", "feed_remove", "feed_list")
+)]
+pub async fn feed(ctx: Context<'_>) -> Result<(), Error> {
+    feed_list(ctx).await
+}
+
+#[poise::command(
+    slash_command,
+    rename = "add",
+    description_localized("en-US", "Subscribe a channel to an RSS/Atom feed")
+)]
+pub async fn feed_add(
+    ctx: Context<'_>,
+    #[description = "RSS/Atom feed URL"] url: String,
+    #[description = "Channel to post new entries into (defaults to this channel)"]
+    channel: Option<ChannelId>,
+    #[description = "Minutes between polls for this feed (defaults to the server setting)"]
+    poll_interval_minutes: Option<u64>,
+) -> Result<(), Error> {
+    check_cooldown(&ctx, "feed", ctx.data().config.cooldowns.github_cooldown).await?;
+    ctx.defer().await?;
+
+    let client = reqwest::Client::builder()
+        .user_agent(&ctx.data().config.github.user_agent)
+        .build()?;
+
+    // Fetch up front both to validate the URL actually resolves to a feed,
+    // and to seed the announced set so subscribing doesn't dump the
+    // existing backlog into the channel.
+    let feed = fetch::fetch_feed(&client, &url).await?;
+    let initial_announced_ids = fetch::all_keys(&feed);
+
+    let channel_id = channel.map(|c| c.get()).unwrap_or_else(|| ctx.channel_id().get());
+
+    let subscription = store::add_subscription(
+        channel_id,
+        ctx.author().id.get(),
+        url.clone(),
+        poll_interval_minutes,
+        initial_announced_ids,
+    )
+    .await;
+
+    let content = format!(
+        "**Subscription #{}** added for <#{}>.\n**Feed:** {}",
+        subscription.id, channel_id, url
+    );
+
+    let embed = create_success_response("Feed Subscribed", &content, false, &ctx.data().config);
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
+
+#[poise::command(
+    slash_command,
+    rename = "remove",
+    description_localized("en-US", "Remove a feed subscription by ID")
+)]
+pub async fn feed_remove(
+    ctx: Context<'_>,
+    #[description = "Subscription ID (see /feed list)"] id: u64,
+) -> Result<(), Error> {
+    check_cooldown(&ctx, "feed", ctx.data().config.cooldowns.github_cooldown).await?;
+
+    let removed = store::remove_subscription(ctx.channel_id().get(), id).await;
+
+    let content = if removed {
+        format!("Removed subscription #{}.", id)
+    } else {
+        format!("No subscription #{} found in this channel.", id)
+    };
+
+    let embed = create_success_response("Feed Unsubscribed", &content, false, &ctx.data().config);
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
+
+#[poise::command(
+    slash_command,
+    rename = "list",
+    description_localized("en-US", "List this channel's feed subscriptions")
+)]
+pub async fn feed_list(ctx: Context<'_>) -> Result<(), Error> {
+    check_cooldown(&ctx, "feed", ctx.data().config.cooldowns.github_cooldown).await?;
+
+    let subscriptions = store::list_subscriptions(ctx.channel_id().get()).await;
+
+    let content = if subscriptions.is_empty() {
+        "No feed subscriptions in this channel.".to_string()
+    } else {
+        subscriptions
+            .iter()
+            .map(|s| {
+                let interval = s
+                    .poll_interval_minutes
+                    .map(|m| format!("{}m", m))
+                    .unwrap_or_else(|| "server default".to_string());
+                format!("**#{}** {} (every {})", s.id, s.url, interval)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let embed = create_success_response("Feed Subscriptions", &content, false, &ctx.data().config);
+    ctx.send(poise::CreateReply::default().embed(embed)).await?;
+    Ok(())
+}
+
+/// Polls every subscription whose own (or the server-default) interval has
+/// elapsed, posting any entries not yet in its announced set and recording
+/// them so they aren't posted again. CVE feed entries are reformatted
+/// through the same `normalize_cve_id`/`format_cve_response` pair the
+/// `/cve` command uses, so advisories posted here carry a CVSS score and
+/// description instead of a bare title/link.
+pub fn start_feed_poller(ctx: Arc<SerenityContext>, config: Arc<Config>) {
+    tokio::spawn(async move {
+        let client = match reqwest::Client::builder()
+            .user_agent(&config.github.user_agent)
+            .build()
+        {
+            Ok(client) => client,
+            Err(e) => {
+                println!("Error building feed poller client: {:?}", e);
+                return;
+            }
+        };
+
+        let mut last_polled: HashMap<u64, Instant> = HashMap::new();
+
+        loop {
+            tokio::time::sleep(POLL_TICK).await;
+            poll_subscriptions(&ctx, &client, &config, &mut last_polled).await;
+        }
+    });
+}
+
+async fn poll_subscriptions(
+    ctx: &SerenityContext,
+    client: &reqwest::Client,
+    config: &Config,
+    last_polled: &mut HashMap<u64, Instant>,
+) {
+    for subscription in store::all_subscriptions().await {
+        let interval = Duration::from_secs(
+            subscription
+                .poll_interval_minutes
+                .unwrap_or(config.feeds.poll_interval_minutes)
+                * 60,
+        );
+
+        let due = last_polled
+            .get(&subscription.id)
+            .map(|polled_at| polled_at.elapsed() >= interval)
+            .unwrap_or(true);
+
+        if !due {
+            continue;
+        }
+        last_polled.insert(subscription.id, Instant::now());
+
+        let feed = match fetch::fetch_feed(client, &subscription.url).await {
+            Ok(feed) => feed,
+            Err(BotError::Feed(msg)) => {
+                println!("Error polling feed {}: {}", subscription.url, msg);
+                continue;
+            }
+            Err(e) => {
+                println!("Error polling feed {}: {:?}", subscription.url, e);
+                continue;
+            }
+        };
+
+        let entries = fetch::new_entries(&feed, &subscription.announced_ids);
+        if entries.is_empty() {
+            continue;
+        }
+
+        let channel = ChannelId::new(subscription.channel_id);
+
+        for item in entries.iter().rev() {
+            let embed = match super::super::cve::normalize_cve_id(&item.title) {
+                Ok(cve_id) => match super::super::cve::fetch_cve_info(&cve_id).await {
+                    Ok(cve_data) => {
+                        let (title, description) =
+                            super::super::cve::format_cve_response(&cve_data, false);
+                        create_info_response(&title, &description, false, config)
+                    }
+                    Err(_) => create_info_response(
+                        &item.title,
+                        item.summary.as_deref().unwrap_or("(no summary)"),
+                        false,
+                        config,
+                    ),
+                },
+                Err(_) => {
+                    let mut content = item.summary.clone().unwrap_or_else(|| "(no summary)".to_string());
+                    if let Some(link) = &item.link {
+                        content.push_str(&format!("\n\n{}", link));
+                    }
+                    create_info_response(&item.title, &content, false, config)
+                }
+            };
+
+            if let Err(e) = channel
+                .send_message(&ctx.http, poise::serenity_prelude::CreateMessage::new().embed(embed))
+                .await
+            {
+                println!(
+                    "Error posting feed entry for subscription {}: {:?}",
+                    subscription.id, e
+                );
+            }
+        }
+
+        store::mark_announced(subscription.id, entries.into_iter().map(|item| item.key)).await;
+    }
+}
@@ -0,0 +1,77 @@
+use std::{collections::HashSet, time::Duration};
+
+use feed_rs::model::{Entry, Feed};
+
+use crate::error::BotError;
+
+pub struct FeedItem {
+    /// Dedup key: the entry's `id`/`guid` when present, otherwise
+    /// `link|published-date`.
+    pub key: String,
+    pub title: String,
+    pub link: Option<String>,
+    pub summary: Option<String>,
+}
+
+pub async fn fetch_feed(client: &reqwest::Client, url: &str) -> Result<Feed, BotError> {
+    let response = client
+        .get(url)
+        .timeout(Duration::from_secs(30))
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(BotError::Feed(format!(
+            "{} returned HTTP {}",
+            url,
+            response.status()
+        )));
+    }
+
+    let bytes = response.bytes().await?;
+
+    feed_rs::parser::parse(&bytes[..])
+        .map_err(|e| BotError::Feed(format!("failed to parse feed at {}: {}", url, e)))
+}
+
+fn entry_key(entry: &Entry) -> String {
+    if !entry.id.is_empty() {
+        return entry.id.clone();
+    }
+
+    let link = entry.links.first().map(|l| l.href.as_str()).unwrap_or("");
+    let published = entry
+        .published
+        .map(|date| date.to_rfc3339())
+        .unwrap_or_default();
+    format!("{}|{}", link, published)
+}
+
+fn to_feed_item(entry: &Entry) -> FeedItem {
+    FeedItem {
+        key: entry_key(entry),
+        title: entry
+            .title
+            .as_ref()
+            .map(|t| t.content.clone())
+            .unwrap_or_else(|| "(untitled)".to_string()),
+        link: entry.links.first().map(|l| l.href.clone()),
+        summary: entry.summary.as_ref().map(|s| s.content.clone()),
+    }
+}
+
+/// Entries in `feed` whose dedup key isn't already in `announced`. Returned
+/// in feed order (newest first, matching `feed-rs`'s own ordering).
+pub fn new_entries(feed: &Feed, announced: &HashSet<String>) -> Vec<FeedItem> {
+    feed.entries
+        .iter()
+        .map(to_feed_item)
+        .filter(|item| !announced.contains(&item.key))
+        .collect()
+}
+
+/// All dedup keys currently in `feed`, used to seed a fresh subscription's
+/// announced set so it doesn't dump the existing backlog into the channel.
+pub fn all_keys(feed: &Feed) -> HashSet<String> {
+    feed.entries.iter().map(entry_key).collect()
+}
@@ -0,0 +1,6 @@
+mod fetch;
+mod store;
+
+mod feed;
+
+pub use feed::{feed, start_feed_poller};
@@ -0,0 +1,112 @@
+use std::{
+    collections::HashSet,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+const FEED_SUBSCRIPTION_FILE: &str = "feed_subscriptions.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedSubscription {
+    pub id: u64,
+    pub channel_id: u64,
+    pub added_by: u64,
+    pub url: String,
+    /// Overrides `config.feeds.poll_interval_minutes` for this feed alone.
+    pub poll_interval_minutes: Option<u64>,
+    /// Dedup keys (see `fetch::FeedItem::key`) of entries already posted,
+    /// persisted so a restart doesn't re-announce old items.
+    pub announced_ids: HashSet<String>,
+}
+
+static FEED_STORE: Lazy<Arc<RwLock<Vec<FeedSubscription>>>> =
+    Lazy::new(|| Arc::new(RwLock::new(load_subscriptions())));
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+fn load_subscriptions() -> Vec<FeedSubscription> {
+    let subscriptions: Vec<FeedSubscription> = std::fs::read_to_string(FEED_SUBSCRIPTION_FILE)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+
+    if let Some(max_id) = subscriptions.iter().map(|s| s.id).max() {
+        NEXT_ID.store(max_id + 1, Ordering::SeqCst);
+    }
+
+    subscriptions
+}
+
+async fn persist(subscriptions: &[FeedSubscription]) {
+    if let Ok(content) = serde_json::to_string_pretty(subscriptions) {
+        if let Err(e) = tokio::fs::write(FEED_SUBSCRIPTION_FILE, content).await {
+            println!("Error saving feed subscriptions: {:?}", e);
+        }
+    }
+}
+
+pub async fn add_subscription(
+    channel_id: u64,
+    added_by: u64,
+    url: String,
+    poll_interval_minutes: Option<u64>,
+    initial_announced_ids: HashSet<String>,
+) -> FeedSubscription {
+    let subscription = FeedSubscription {
+        id: NEXT_ID.fetch_add(1, Ordering::SeqCst),
+        channel_id,
+        added_by,
+        url,
+        poll_interval_minutes,
+        announced_ids: initial_announced_ids,
+    };
+
+    let mut subscriptions = FEED_STORE.write().await;
+    subscriptions.push(subscription.clone());
+    persist(&subscriptions).await;
+
+    subscription
+}
+
+pub async fn list_subscriptions(channel_id: u64) -> Vec<FeedSubscription> {
+    FEED_STORE
+        .read()
+        .await
+        .iter()
+        .filter(|s| s.channel_id == channel_id)
+        .cloned()
+        .collect()
+}
+
+pub async fn remove_subscription(channel_id: u64, id: u64) -> bool {
+    let mut subscriptions = FEED_STORE.write().await;
+    let before = subscriptions.len();
+    subscriptions.retain(|s| !(s.id == id && s.channel_id == channel_id));
+    let removed = subscriptions.len() != before;
+
+    if removed {
+        persist(&subscriptions).await;
+    }
+
+    removed
+}
+
+pub async fn all_subscriptions() -> Vec<FeedSubscription> {
+    FEED_STORE.read().await.clone()
+}
+
+/// Records `keys` as announced for subscription `id` so they aren't posted
+/// again on the next poll or after a restart.
+pub async fn mark_announced(id: u64, keys: impl IntoIterator<Item = String>) {
+    let mut subscriptions = FEED_STORE.write().await;
+    if let Some(subscription) = subscriptions.iter_mut().find(|s| s.id == id) {
+        subscription.announced_ids.extend(keys);
+        persist(&subscriptions).await;
+    }
+}
@@ -0,0 +1,5 @@
+pub mod cve;
+pub mod feeds;
+
+pub use cve::cve;
+pub use feeds::{feed, start_feed_poller};
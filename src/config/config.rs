@@ -9,6 +9,11 @@ pub struct Config {
     pub cooldowns: CooldownConfig,
     pub quotes: QuotesConfig,
     pub github: GitHubConfig,
+    pub feeds: FeedsConfig,
+    pub database: DatabaseConfig,
+    pub error_reporting: ErrorReportingConfig,
+    pub audio: AudioConfig,
+    pub js: JsConfig,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -30,6 +35,20 @@ pub struct CooldownConfig {
     pub hash_cooldown: u64,
     pub github_cooldown: u64,
     pub color_cooldown: u64,
+    pub backend: CooldownBackend,
+    pub redis_url: Option<String>,
+}
+
+/// Where `check_cooldown` persists its state. `Postgres` reuses
+/// `database.connection_string`; `Redis` requires `redis_url` to be set.
+/// Both fall back to the in-memory behavior at startup if their backing
+/// store can't be reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CooldownBackend {
+    Memory,
+    Redis,
+    Postgres,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -42,6 +61,50 @@ pub struct QuotesConfig {
 pub struct GitHubConfig {
     pub user_agent: String,
     pub token: Option<String>,
+    pub cache_ttl_seconds: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FeedsConfig {
+    pub poll_interval_minutes: u64,
+}
+
+/// Optional Postgres-backed persistence for the CVE cache and cooldown
+/// tracking. Leave `connection_string` unset to keep today's in-memory
+/// behavior for single-instance deployments.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DatabaseConfig {
+    pub connection_string: Option<String>,
+    pub pool_size: u32,
+}
+
+/// Opt-in crash reporting: demangled backtraces for command errors/panics
+/// get uploaded to an S3-compatible bucket so operators can triage them.
+/// Leave `enabled` false to keep today's "print to stdout" behavior.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ErrorReportingConfig {
+    pub enabled: bool,
+    pub s3_endpoint: Option<String>,
+    pub s3_bucket: Option<String>,
+    pub s3_access_key: Option<String>,
+    pub s3_secret_key: Option<String>,
+    pub object_expiry_days: u64,
+}
+
+/// Bounds and SoundFont selection for `/midi`, mirroring `LimitsConfig`'s
+/// role of keeping one heavy command from chewing through memory/CPU.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AudioConfig {
+    pub max_midi_size: usize,
+    pub max_duration_seconds: u64,
+    pub soundfont_path: String,
+}
+
+/// Wall-clock bound for `/js`, mirroring `AudioConfig`'s role of keeping one
+/// heavy (and here, user-controlled-code) command from hanging the executor.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct JsConfig {
+    pub timeout_seconds: u64,
 }
 
 impl Default for Config {
@@ -61,6 +124,8 @@ impl Default for Config {
                 hash_cooldown: 5,
                 github_cooldown: 10,
                 color_cooldown: 2,
+                backend: CooldownBackend::Memory,
+                redis_url: None,
             },
             quotes: QuotesConfig {
                 quotes: vec![
@@ -87,6 +152,30 @@ impl Default for Config {
             github: GitHubConfig {
                 user_agent: "Arisa-Bot/1.0".to_string(),
                 token: None,
+                cache_ttl_seconds: 300,
+            },
+            feeds: FeedsConfig {
+                poll_interval_minutes: 15,
+            },
+            database: DatabaseConfig {
+                connection_string: None,
+                pool_size: 10,
+            },
+            error_reporting: ErrorReportingConfig {
+                enabled: false,
+                s3_endpoint: None,
+                s3_bucket: None,
+                s3_access_key: None,
+                s3_secret_key: None,
+                object_expiry_days: 30,
+            },
+            audio: AudioConfig {
+                max_midi_size: 1_048_576,
+                max_duration_seconds: 180,
+                soundfont_path: "soundfonts/default.sf2".to_string(),
+            },
+            js: JsConfig {
+                timeout_seconds: 5,
             },
         }
     }
@@ -8,10 +8,14 @@ pub enum BotError {
     Serialization(serde_json::Error),
     InvalidFormat(String),
     GitHub(String),
+    Http(String),
     Color(String),
     Cooldown(u64),
     Config(String),
     Serenity(poise::serenity_prelude::Error),
+    RateLimited { reset_at: chrono::DateTime<chrono::Utc> },
+    Feed(String),
+    Storage(String),
 }
 
 impl fmt::Display for BotError {
@@ -23,10 +27,18 @@ impl fmt::Display for BotError {
             BotError::Serialization(e) => write!(f, "Serialization error: {}", e),
             BotError::InvalidFormat(msg) => write!(f, "Invalid format: {}", msg),
             BotError::GitHub(msg) => write!(f, "GitHub error: {}", msg),
+            BotError::Http(msg) => write!(f, "HTTP error: {}", msg),
             BotError::Color(msg) => write!(f, "Color error: {}", msg),
             BotError::Cooldown(seconds) => write!(f, "Command on cooldown for {} seconds", seconds),
             BotError::Config(msg) => write!(f, "Configuration error: {}", msg),
             BotError::Serenity(e) => write!(f, "Discord error: {}", e),
+            BotError::RateLimited { reset_at } => write!(
+                f,
+                "Rate limited, try again at {}",
+                reset_at.format("%H:%M UTC")
+            ),
+            BotError::Feed(msg) => write!(f, "Feed error: {}", msg),
+            BotError::Storage(msg) => write!(f, "Storage error: {}", msg),
         }
     }
 }
@@ -7,16 +7,19 @@ mod util;
 use std::sync::Arc;
 
 use command::{
-    crypto::{checksum, hash, uuid},
-    encoding::{base64, endian, rot, timestamp, url},
+    audio::midi,
+    crypto::{aead, checksum, hash, hkdf, hmac, uuid},
+    encoding::{base64, endian, recipe, rot, timestamp, url},
     java::{jep, opcode},
-    misc::{color, github, hawktuah, help},
-    security::cve,
+    misc::{color, github, hawktuah, help, js},
+    reminders::{interval, remind, start_reminder_scheduler},
+    security::{cve, feed, start_feed_poller},
 };
-use config::Config;
+use config::{Config, CooldownBackend};
 use error::BotError;
 use poise::serenity_prelude as serenity;
 use util::cooldown::CooldownManager;
+use util::cooldown_store::{CooldownStore, PostgresCooldownStore, RedisCooldownStore, memory_store};
 
 type Error = BotError;
 type Context<'a> = poise::Context<'a, Data, Error>;
@@ -25,13 +28,15 @@ type Context<'a> = poise::Context<'a, Data, Error>;
 pub struct Data {
     pub config: Arc<Config>,
     pub cooldown_manager: CooldownManager,
+    pub db_pool: Option<util::db::PgPool>,
+    pub github_client: Arc<util::github_client::GitHubClient>,
 }
 
 async fn on_error(error: poise::FrameworkError<'_, Data, Error>) {
     match error {
         poise::FrameworkError::Setup { error, .. } => panic!("Failed to start bot: {:?}", error),
         poise::FrameworkError::Command { error, ctx, .. } => {
-            let error_msg = match &error {
+            let mut error_msg = match &error {
                 BotError::Cooldown(seconds) => {
                     format!("⏰ Command on cooldown for {} seconds", seconds)
                 }
@@ -41,6 +46,15 @@ async fn on_error(error: poise::FrameworkError<'_, Data, Error>) {
                 _ => format!("❌ Error: {}", error),
             };
 
+            let report_id = util::crash_report::report_error(
+                &ctx.data().config,
+                Some(ctx.command().qualified_name.clone()),
+                Some(ctx.author().id.get()),
+                error.to_string(),
+                format!("{:?}", error),
+            );
+            error_msg.push_str(&format!("\n\n*Report ID: `{}`*", report_id));
+
             let embed = util::command::create_error_response("Command Error", &error_msg);
             if let Err(e) = ctx
                 .send(poise::CreateReply::default().embed(embed).ephemeral(true))
@@ -70,6 +84,8 @@ async fn on_ready(
     let initial_status = util::quote::get_random_status();
     ctx.set_presence(Some(initial_activity), initial_status);
     util::status::start_status_updater(ctx.clone().into(), data.config.clone());
+    start_reminder_scheduler(ctx.clone().into());
+    start_feed_poller(ctx.clone().into(), data.config.clone());
 
     Ok(())
 }
@@ -86,7 +102,47 @@ async fn main() {
         panic!("Discord token not set in config.toml");
     }
 
-    let cooldown_manager = CooldownManager::new();
+    {
+        let panic_config = config.clone();
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |panic_info| {
+            default_hook(panic_info);
+            util::crash_report::report_error(
+                &panic_config,
+                None,
+                None,
+                panic_info.to_string(),
+                panic_info.to_string(),
+            );
+        }));
+    }
+
+    let db_pool = util::db::init_pool(&config.database).await;
+
+    let cooldown_store: Arc<dyn CooldownStore> = match config.cooldowns.backend {
+        CooldownBackend::Memory => memory_store(),
+        CooldownBackend::Redis => match &config.cooldowns.redis_url {
+            Some(redis_url) => match RedisCooldownStore::connect(redis_url).await {
+                Ok(store) => Arc::new(store),
+                Err(e) => {
+                    println!("Failed to connect Redis cooldown store, falling back to in-memory: {:?}", e);
+                    memory_store()
+                }
+            },
+            None => {
+                println!("Redis cooldown backend selected but `redis_url` is unset, falling back to in-memory");
+                memory_store()
+            }
+        },
+        CooldownBackend::Postgres => match &db_pool {
+            Some(pool) => Arc::new(PostgresCooldownStore::new(pool.clone())),
+            None => {
+                println!("Postgres cooldown backend selected but no database is configured, falling back to in-memory");
+                memory_store()
+            }
+        },
+    };
+    let cooldown_manager = CooldownManager::new(cooldown_store);
 
     let cleanup_manager = cooldown_manager.clone();
     tokio::spawn(async move {
@@ -97,9 +153,17 @@ async fn main() {
         }
     });
 
+    let github_client = Arc::new(util::github_client::GitHubClient::new(
+        config.github.token.clone(),
+        config.github.user_agent.clone(),
+        config.github.cache_ttl_seconds,
+    ));
+
     let data = Data {
         config: config.clone(),
         cooldown_manager,
+        db_pool,
+        github_client,
     };
 
     let intents =
@@ -117,12 +181,21 @@ async fn main() {
                 hash(),
                 checksum(),
                 uuid(),
+                hmac(),
+                hkdf(),
+                aead(),
                 github(),
                 color(),
                 hawktuah(),
                 jep(),
                 opcode(),
+                js(),
                 cve(),
+                remind(),
+                interval(),
+                feed(),
+                midi(),
+                recipe(),
             ],
             prefix_options: poise::PrefixFrameworkOptions {
                 prefix: None,
@@ -0,0 +1,154 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use bb8_redis::{
+    RedisConnectionManager,
+    redis::{self, AsyncCommands},
+};
+use tokio::sync::RwLock;
+
+use crate::error::{BotError, Result};
+use crate::util::db::{self, PgPool};
+
+/// Backs `check_cooldown`. Implementations are expected to fail open
+/// (return `Ok(())`) when their backing store is unreachable, rather than
+/// let a storage outage take every rate-limited command down with it.
+#[async_trait]
+pub trait CooldownStore: Send + Sync {
+    async fn check_and_set(&self, command: &str, user_id: u64, cooldown_seconds: u64) -> Result<()>;
+
+    /// Prunes stale entries. Backends whose entries expire on their own
+    /// (Redis `PX`, Postgres's `expires_at` guard) can leave this as the
+    /// default no-op; the in-memory backend needs it to avoid growing
+    /// forever.
+    async fn cleanup_expired(&self, _max_age_seconds: u64) {}
+}
+
+/// The original behavior: cooldowns live only as long as the process does.
+pub struct MemoryCooldownStore {
+    cooldowns: RwLock<HashMap<String, HashMap<u64, Instant>>>,
+}
+
+impl MemoryCooldownStore {
+    pub fn new() -> Self {
+        Self {
+            cooldowns: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for MemoryCooldownStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CooldownStore for MemoryCooldownStore {
+    async fn check_and_set(&self, command: &str, user_id: u64, cooldown_seconds: u64) -> Result<()> {
+        let mut cooldowns = self.cooldowns.write().await;
+        let command_cooldowns = cooldowns.entry(command.to_string()).or_insert_with(HashMap::new);
+
+        if let Some(&last_used) = command_cooldowns.get(&user_id) {
+            let elapsed = last_used.elapsed();
+            let cooldown_duration = Duration::from_secs(cooldown_seconds);
+
+            if elapsed < cooldown_duration {
+                let remaining = cooldown_duration - elapsed;
+                return Err(BotError::Cooldown(remaining.as_secs()));
+            }
+        }
+
+        command_cooldowns.insert(user_id, Instant::now());
+        Ok(())
+    }
+
+    async fn cleanup_expired(&self, max_age_seconds: u64) {
+        let mut cooldowns = self.cooldowns.write().await;
+        let cutoff = Instant::now() - Duration::from_secs(max_age_seconds);
+
+        for command_cooldowns in cooldowns.values_mut() {
+            command_cooldowns.retain(|_, &mut last_used| last_used > cutoff);
+        }
+
+        cooldowns.retain(|_, command_cooldowns| !command_cooldowns.is_empty());
+    }
+}
+
+/// Shares cooldown state across shards/instances via Redis. Uses
+/// `SET key 1 NX PX <ms>` so the check and the expiry are a single atomic
+/// operation instead of a separate read-then-write.
+pub struct RedisCooldownStore {
+    pool: bb8::Pool<RedisConnectionManager>,
+}
+
+impl RedisCooldownStore {
+    pub async fn connect(redis_url: &str) -> Result<Self> {
+        let manager = RedisConnectionManager::new(redis_url)
+            .map_err(|e| BotError::Storage(format!("invalid redis url: {}", e)))?;
+        let pool = bb8::Pool::builder()
+            .build(manager)
+            .await
+            .map_err(|e| BotError::Storage(format!("failed to build redis pool: {}", e)))?;
+
+        Ok(Self { pool })
+    }
+}
+
+#[async_trait]
+impl CooldownStore for RedisCooldownStore {
+    async fn check_and_set(&self, command: &str, user_id: u64, cooldown_seconds: u64) -> Result<()> {
+        let Ok(mut conn) = self.pool.get().await else {
+            return Ok(());
+        };
+
+        let key = format!("cooldown:{}:{}", command, user_id);
+        let acquired: redis::RedisResult<bool> = redis::cmd("SET")
+            .arg(&key)
+            .arg(1)
+            .arg("NX")
+            .arg("PX")
+            .arg(cooldown_seconds * 1000)
+            .query_async(&mut *conn)
+            .await;
+
+        match acquired {
+            Ok(true) => Ok(()),
+            Ok(false) => {
+                let remaining_ms: i64 = conn.pttl(&key).await.unwrap_or(0);
+                Err(BotError::Cooldown((remaining_ms.max(0) as u64) / 1000))
+            }
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+/// Shares cooldown state across shards/instances via Postgres. Delegates to
+/// `util::db::check_and_set_cooldown`, which upserts `expires_at` guarded
+/// by the existing row already having expired.
+pub struct PostgresCooldownStore {
+    pool: PgPool,
+}
+
+impl PostgresCooldownStore {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CooldownStore for PostgresCooldownStore {
+    async fn check_and_set(&self, command: &str, user_id: u64, cooldown_seconds: u64) -> Result<()> {
+        db::check_and_set_cooldown(&self.pool, command, user_id, cooldown_seconds)
+            .await
+            .map_err(BotError::Cooldown)
+    }
+}
+
+pub fn memory_store() -> Arc<dyn CooldownStore> {
+    Arc::new(MemoryCooldownStore::new())
+}
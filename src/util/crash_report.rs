@@ -0,0 +1,135 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use backtrace::Backtrace;
+use s3::{Bucket, Region, creds::Credentials};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::config::{Config, ErrorReportingConfig};
+
+#[derive(Debug, Serialize)]
+struct CrashReport {
+    report_id: String,
+    command: Option<String>,
+    user_id: Option<u64>,
+    error_display: String,
+    error_debug: String,
+    timestamp: u64,
+    frames: Vec<String>,
+}
+
+/// Walks the current backtrace and demangles every symbol so the uploaded
+/// report reads like a normal Rust panic backtrace instead of raw `_ZN...`
+/// mangled names.
+fn capture_demangled_frames() -> Vec<String> {
+    let backtrace = Backtrace::new();
+    backtrace
+        .frames()
+        .iter()
+        .flat_map(|frame| frame.symbols())
+        .map(|symbol| match symbol.name() {
+            Some(name) => rustc_demangle::demangle(&name.to_string()).to_string(),
+            None => "<unknown>".to_string(),
+        })
+        .collect()
+}
+
+/// Builds a crash report and, when `config.error_reporting.enabled`,
+/// uploads it to the configured S3-compatible bucket on a spawned task so
+/// the caller never waits on that network I/O. The report id is generated
+/// locally rather than by the upload, so it's ready to put straight into
+/// the ephemeral error embed regardless of whether the upload succeeds.
+pub fn report_error(
+    config: &Config,
+    command: Option<String>,
+    user_id: Option<u64>,
+    error_display: String,
+    error_debug: String,
+) -> String {
+    let report_id = Uuid::new_v4().to_string();
+
+    if config.error_reporting.enabled {
+        let report = CrashReport {
+            report_id: report_id.clone(),
+            command,
+            user_id,
+            error_display,
+            error_debug,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            frames: capture_demangled_frames(),
+        };
+
+        let reporting_config = config.error_reporting.clone();
+        tokio::spawn(async move {
+            upload_report(&reporting_config, &report).await;
+        });
+    }
+
+    report_id
+}
+
+async fn upload_report(config: &ErrorReportingConfig, report: &CrashReport) {
+    let (Some(endpoint), Some(bucket_name), Some(access_key), Some(secret_key)) = (
+        &config.s3_endpoint,
+        &config.s3_bucket,
+        &config.s3_access_key,
+        &config.s3_secret_key,
+    ) else {
+        println!("Error reporting is enabled but S3 credentials are incomplete; skipping upload");
+        return;
+    };
+
+    let region = Region::Custom {
+        region: "us-east-1".to_string(),
+        endpoint: endpoint.clone(),
+    };
+
+    let credentials = match Credentials::new(Some(access_key), Some(secret_key), None, None, None) {
+        Ok(credentials) => credentials,
+        Err(e) => {
+            println!("Error building S3 credentials: {:?}", e);
+            return;
+        }
+    };
+
+    let bucket = match Bucket::new(bucket_name, region, credentials) {
+        Ok(bucket) => bucket,
+        Err(e) => {
+            println!("Error constructing S3 bucket client: {:?}", e);
+            return;
+        }
+    };
+
+    let body = match serde_json::to_vec(report) {
+        Ok(body) => body,
+        Err(e) => {
+            println!("Error serializing crash report {}: {:?}", report.report_id, e);
+            return;
+        }
+    };
+
+    let key = format!("crash-reports/{}.json", report.report_id);
+
+    if let Err(e) = bucket
+        .put_object_with_content_type(&key, &body, "application/json")
+        .await
+    {
+        println!("Error uploading crash report {}: {:?}", report.report_id, e);
+        return;
+    }
+
+    let expiry_days = config.object_expiry_days.to_string();
+    let tags = [("expires-in-days", expiry_days.as_str())];
+
+    if let Err(e) = bucket.put_object_tagging(&key, &tags).await {
+        println!("Error tagging crash report {}: {:?}", report.report_id, e);
+    }
+
+    println!(
+        "Uploaded crash report {} to s3://{}/{}",
+        report.report_id, bucket_name, key
+    );
+}
@@ -1,6 +1,10 @@
+use std::hash::Hasher as StdHasher;
+
 use blake3::Hasher as Blake3Hasher;
+use crc32fast::Hasher as Crc32Hasher;
 use sha1::Sha1;
 use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+use twox_hash::XxHash64;
 
 #[derive(Debug, Clone)]
 pub enum HashAlgorithm {
@@ -80,3 +84,113 @@ pub fn calculate_checksum(input: &[u8]) -> u32 {
 pub fn calculate_adler32(input: &[u8]) -> u32 {
     adler::adler32_slice(input)
 }
+
+/// A single running Adler-32 accumulator, kept by hand instead of via the
+/// `adler` crate so it can be folded incrementally over a byte stream.
+#[derive(Clone, Copy)]
+struct Adler32State {
+    a: u32,
+    b: u32,
+}
+
+impl Adler32State {
+    const MOD_ADLER: u32 = 65521;
+
+    fn new() -> Self {
+        Self { a: 1, b: 0 }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.a = (self.a + byte as u32) % Self::MOD_ADLER;
+            self.b = (self.b + self.a) % Self::MOD_ADLER;
+        }
+    }
+
+    fn finalize(self) -> u32 {
+        (self.b << 16) | self.a
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChecksumKind {
+    Crc32,
+    Adler32,
+    Crc32c,
+    XxHash64,
+    Sha256,
+}
+
+impl ChecksumKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChecksumKind::Crc32 => "CRC32",
+            ChecksumKind::Adler32 => "Adler32",
+            ChecksumKind::Crc32c => "CRC32C",
+            ChecksumKind::XxHash64 => "xxHash64",
+            ChecksumKind::Sha256 => "SHA-256",
+        }
+    }
+}
+
+/// Folds every active algorithm over a byte stream in a single pass, so a
+/// large download never has to be buffered whole just to be checksummed.
+pub struct ChecksumStream {
+    crc32: Option<Crc32Hasher>,
+    adler32: Option<Adler32State>,
+    crc32c: Option<u32>,
+    xxhash64: Option<XxHash64>,
+    sha256: Option<Sha256>,
+}
+
+impl ChecksumStream {
+    pub fn new(kinds: &[ChecksumKind]) -> Self {
+        Self {
+            crc32: kinds.contains(&ChecksumKind::Crc32).then(Crc32Hasher::new),
+            adler32: kinds.contains(&ChecksumKind::Adler32).then(Adler32State::new),
+            crc32c: kinds.contains(&ChecksumKind::Crc32c).then_some(0),
+            xxhash64: kinds
+                .contains(&ChecksumKind::XxHash64)
+                .then(|| XxHash64::with_seed(0)),
+            sha256: kinds.contains(&ChecksumKind::Sha256).then(Sha256::new),
+        }
+    }
+
+    pub fn update(&mut self, chunk: &[u8]) {
+        if let Some(hasher) = &mut self.crc32 {
+            hasher.update(chunk);
+        }
+        if let Some(state) = &mut self.adler32 {
+            state.update(chunk);
+        }
+        if let Some(crc) = &mut self.crc32c {
+            *crc = crc32c::crc32c_append(*crc, chunk);
+        }
+        if let Some(hasher) = &mut self.xxhash64 {
+            hasher.write(chunk);
+        }
+        if let Some(hasher) = &mut self.sha256 {
+            hasher.update(chunk);
+        }
+    }
+
+    pub fn finalize(self) -> Vec<(ChecksumKind, String)> {
+        let mut results = Vec::new();
+        if let Some(hasher) = self.crc32 {
+            results.push((ChecksumKind::Crc32, format!("{:08x}", hasher.finalize())));
+        }
+        if let Some(state) = self.adler32 {
+            results.push((ChecksumKind::Adler32, format!("{:08x}", state.finalize())));
+        }
+        if let Some(crc) = self.crc32c {
+            results.push((ChecksumKind::Crc32c, format!("{:08x}", crc)));
+        }
+        if let Some(hasher) = self.xxhash64 {
+            results.push((ChecksumKind::XxHash64, format!("{:016x}", hasher.finish())));
+        }
+        if let Some(hasher) = self.sha256 {
+            results.push((ChecksumKind::Sha256, format!("{:x}", hasher.finalize())));
+        }
+        results
+    }
+}
@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use bb8::Pool;
+use bb8_postgres::PostgresConnectionManager;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, de::DeserializeOwned};
+use tokio_postgres::NoTls;
+
+use crate::config::DatabaseConfig;
+
+pub type PgPool = Pool<PostgresConnectionManager<NoTls>>;
+
+/// Builds the connection pool and ensures the tables it's backing exist.
+/// Returns `None` (rather than an error) when no connection string is
+/// configured, so callers can fall back to in-memory behavior uniformly.
+pub async fn init_pool(config: &DatabaseConfig) -> Option<PgPool> {
+    let connection_string = config.connection_string.as_ref()?;
+
+    let pg_config: tokio_postgres::Config = match connection_string.parse() {
+        Ok(pg_config) => pg_config,
+        Err(e) => {
+            println!("Invalid database connection string: {:?}", e);
+            return None;
+        }
+    };
+
+    let manager = PostgresConnectionManager::new(pg_config, NoTls);
+
+    let pool = match Pool::builder()
+        .max_size(config.pool_size)
+        .build(manager)
+        .await
+    {
+        Ok(pool) => pool,
+        Err(e) => {
+            println!("Failed to build database pool: {:?}", e);
+            return None;
+        }
+    };
+
+    if let Err(e) = run_migrations(&pool).await {
+        println!("Failed to run database migrations: {:?}", e);
+        return None;
+    }
+
+    Some(pool)
+}
+
+async fn run_migrations(pool: &PgPool) -> Result<(), bb8::RunError<tokio_postgres::Error>> {
+    let conn = pool.get().await?;
+    conn.batch_execute(
+        "CREATE TABLE IF NOT EXISTS cve_cache (
+            cve_id TEXT PRIMARY KEY,
+            json TEXT NOT NULL,
+            cached_at TIMESTAMPTZ NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS cooldowns (
+            command TEXT NOT NULL,
+            user_id BIGINT NOT NULL,
+            expires_at TIMESTAMPTZ NOT NULL,
+            PRIMARY KEY (command, user_id)
+        );",
+    )
+    .await?;
+    Ok(())
+}
+
+pub async fn get_cached_cve<T: DeserializeOwned>(
+    pool: &PgPool,
+    cve_id: &str,
+    max_age: Duration,
+) -> Option<T> {
+    let conn = pool.get().await.ok()?;
+    let row = conn
+        .query_opt(
+            "SELECT json, cached_at FROM cve_cache WHERE cve_id = $1",
+            &[&cve_id],
+        )
+        .await
+        .ok()??;
+
+    let json: String = row.get("json");
+    let cached_at: DateTime<Utc> = row.get("cached_at");
+
+    if Utc::now() - cached_at > chrono::Duration::from_std(max_age).ok()? {
+        return None;
+    }
+
+    serde_json::from_str(&json).ok()
+}
+
+pub async fn cache_cve<T: Serialize>(pool: &PgPool, cve_id: &str, value: &T) {
+    let Ok(conn) = pool.get().await else {
+        return;
+    };
+    let Ok(json) = serde_json::to_string(value) else {
+        return;
+    };
+
+    if let Err(e) = conn
+        .execute(
+            "INSERT INTO cve_cache (cve_id, json, cached_at) VALUES ($1, $2, now())
+             ON CONFLICT (cve_id) DO UPDATE SET json = EXCLUDED.json, cached_at = EXCLUDED.cached_at",
+            &[&cve_id, &json],
+        )
+        .await
+    {
+        println!("Error caching {} to database: {:?}", cve_id, e);
+    }
+}
+
+/// Returns `Err(remaining_seconds)` when the caller is still on cooldown.
+/// Pool errors fail open (`Ok(())`) since a cooldown check should never be
+/// the reason a command becomes entirely unusable.
+///
+/// The upsert's `WHERE` clause only lets the new `expires_at` land when the
+/// existing row (if any) has already expired, so the check-and-set is a
+/// single atomic statement rather than a separate read then write.
+pub async fn check_and_set_cooldown(
+    pool: &PgPool,
+    command: &str,
+    user_id: u64,
+    cooldown_seconds: u64,
+) -> Result<(), u64> {
+    let Ok(conn) = pool.get().await else {
+        return Ok(());
+    };
+
+    let claimed = conn
+        .query_opt(
+            "INSERT INTO cooldowns (command, user_id, expires_at)
+             VALUES ($1, $2, now() + ($3::bigint * interval '1 second'))
+             ON CONFLICT (command, user_id) DO UPDATE
+                 SET expires_at = EXCLUDED.expires_at
+             WHERE cooldowns.expires_at <= now()
+             RETURNING expires_at",
+            &[&command, &(user_id as i64), &(cooldown_seconds as i64)],
+        )
+        .await;
+
+    match claimed {
+        Ok(Some(_)) => Ok(()),
+        Ok(None) => {
+            let existing = conn
+                .query_opt(
+                    "SELECT expires_at FROM cooldowns WHERE command = $1 AND user_id = $2",
+                    &[&command, &(user_id as i64)],
+                )
+                .await;
+
+            let remaining = match existing {
+                Ok(Some(row)) => {
+                    let expires_at: DateTime<Utc> = row.get("expires_at");
+                    (expires_at - Utc::now()).num_seconds().max(0) as u64
+                }
+                _ => cooldown_seconds,
+            };
+
+            Err(remaining)
+        }
+        Err(_) => Ok(()),
+    }
+}
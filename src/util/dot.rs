@@ -0,0 +1,80 @@
+/// Whether edges render as `->` (directed) or `--` (undirected) Graphviz DOT.
+#[derive(Debug, Clone, Copy)]
+pub enum Kind {
+    Digraph,
+    Graph,
+}
+
+impl Kind {
+    fn keyword(&self) -> &'static str {
+        match self {
+            Kind::Digraph => "digraph",
+            Kind::Graph => "graph",
+        }
+    }
+
+    fn edge_operator(&self) -> &'static str {
+        match self {
+            Kind::Digraph => "->",
+            Kind::Graph => "--",
+        }
+    }
+}
+
+/// A minimal Graphviz DOT builder — just enough to emit labeled nodes and
+/// edges for a small graph, not a general-purpose DOT AST.
+pub struct DotBuilder {
+    kind: Kind,
+    name: String,
+    body: String,
+}
+
+impl DotBuilder {
+    pub fn new(kind: Kind, name: &str) -> Self {
+        Self {
+            kind,
+            name: name.to_string(),
+            body: String::new(),
+        }
+    }
+
+    pub fn node(&mut self, id: &str, label: &str) -> &mut Self {
+        self.writeln(&format!("\"{}\" [label=\"{}\"];", escape(id), escape(label)));
+        self
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str, label: &str) -> &mut Self {
+        let operator = self.kind.edge_operator();
+        self.writeln(&format!(
+            "\"{}\" {} \"{}\" [label=\"{}\"];",
+            escape(from),
+            operator,
+            escape(to),
+            escape(label)
+        ));
+        self
+    }
+
+    fn writeln(&mut self, line: &str) {
+        self.body.push_str("  ");
+        self.body.push_str(line);
+        self.body.push('\n');
+    }
+
+    pub fn build(self) -> String {
+        format!(
+            "{} \"{}\" {{\n{}}}\n",
+            self.kind.keyword(),
+            escape(&self.name),
+            self.body
+        )
+    }
+}
+
+/// Escapes characters DOT treats specially inside a quoted string.
+pub fn escape(input: &str) -> String {
+    input
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
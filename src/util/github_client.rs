@@ -0,0 +1,104 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use reqwest::{Client, StatusCode};
+use serde::de::DeserializeOwned;
+use tokio::sync::RwLock;
+
+use crate::error::BotError;
+
+struct CachedResponse {
+    value: serde_json::Value,
+    fetched_at: Instant,
+}
+
+/// Authenticated GitHub REST API client shared via `ctx.data()`.
+///
+/// Sends `config.github.token` (when set) as an `Authorization: Bearer`
+/// header to get the authenticated 5000/hour rate limit instead of the
+/// unauthenticated 60/hour-per-IP one, and wraps responses in a short-lived
+/// in-memory cache keyed by request URL so a burst of lookups for the same
+/// user/repo doesn't each count against the limit.
+pub struct GitHubClient {
+    client: Client,
+    token: Option<String>,
+    user_agent: String,
+    ttl: Duration,
+    cache: RwLock<HashMap<String, CachedResponse>>,
+}
+
+impl GitHubClient {
+    pub fn new(token: Option<String>, user_agent: String, ttl_seconds: u64) -> Self {
+        Self {
+            client: Client::new(),
+            token,
+            user_agent,
+            ttl: Duration::from_secs(ttl_seconds),
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Fetches and deserializes `url`, serving a cached value if it was
+    /// fetched within the configured TTL. On a 403/429 with
+    /// `x-ratelimit-remaining: 0`, returns `BotError::RateLimited` instead
+    /// of the generic HTTP error so callers can render a friendly retry
+    /// message.
+    pub async fn get<T: DeserializeOwned>(&self, url: &str) -> Result<T, BotError> {
+        {
+            let cache = self.cache.read().await;
+            if let Some(cached) = cache.get(url) {
+                if cached.fetched_at.elapsed() < self.ttl {
+                    return serde_json::from_value(cached.value.clone())
+                        .map_err(BotError::Serialization);
+                }
+            }
+        }
+
+        let mut request = self.client.get(url).header("User-Agent", &self.user_agent);
+        if let Some(token) = &self.token {
+            request = request.header("Authorization", format!("Bearer {}", token));
+        }
+
+        let response = request.send().await?;
+        let headers = response.headers().clone();
+        let status = response.status();
+
+        if status == StatusCode::FORBIDDEN || status == StatusCode::TOO_MANY_REQUESTS {
+            let remaining = header_u32(&headers, "x-ratelimit-remaining");
+            if remaining == Some(0) {
+                let reset_at = header_i64(&headers, "x-ratelimit-reset")
+                    .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+                    .unwrap_or_else(chrono::Utc::now);
+                return Err(BotError::RateLimited { reset_at });
+            }
+        }
+
+        if !status.is_success() {
+            return Err(BotError::GitHub(format!("{} returned HTTP {}", url, status)));
+        }
+
+        let value: serde_json::Value = response.json().await?;
+        let parsed: T = serde_json::from_value(value.clone()).map_err(BotError::Serialization)?;
+
+        let mut cache = self.cache.write().await;
+        cache.insert(
+            url.to_string(),
+            CachedResponse {
+                value,
+                fetched_at: Instant::now(),
+            },
+        );
+
+        Ok(parsed)
+    }
+}
+
+fn header_u32(headers: &reqwest::header::HeaderMap, name: &str) -> Option<u32> {
+    headers.get(name)?.to_str().ok()?.parse().ok()
+}
+
+fn header_i64(headers: &reqwest::header::HeaderMap, name: &str) -> Option<i64> {
+    headers.get(name)?.to_str().ok()?.parse().ok()
+}
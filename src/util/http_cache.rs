@@ -0,0 +1,136 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::Instant,
+};
+
+use reqwest::{Client, StatusCode, header};
+use tokio::sync::RwLock;
+
+use crate::error::BotError;
+
+/// Maximum number of distinct URLs a cache keeps entries for by default.
+/// Once exceeded, the least-recently-used entry is evicted to make room.
+const DEFAULT_MAX_ENTRIES: usize = 256;
+
+struct CachedEntry<T> {
+    value: T,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    last_used: Instant,
+}
+
+/// A conditional-GET HTTP cache shared by fetchers that want to avoid
+/// re-downloading and re-parsing a resource that hasn't changed upstream.
+///
+/// Stores the `ETag`/`Last-Modified` validators an origin sent back and
+/// replays them as `If-None-Match`/`If-Modified-Since` on the next request.
+/// A `304 Not Modified` response reuses the last parsed value instead of
+/// re-parsing the body, so callers only pay the parse cost when the
+/// resource actually changed.
+pub struct HttpCache<T> {
+    entries: Arc<RwLock<HashMap<String, CachedEntry<T>>>>,
+    max_entries: usize,
+}
+
+impl<T: Clone + Send + Sync> HttpCache<T> {
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_MAX_ENTRIES)
+    }
+
+    pub fn with_capacity(max_entries: usize) -> Self {
+        Self {
+            entries: Arc::new(RwLock::new(HashMap::new())),
+            max_entries,
+        }
+    }
+
+    /// Fetches `url`, replaying any stored validators as conditional
+    /// headers. On a `304`, the cached value is returned without calling
+    /// `parse`. On a fresh `200`, the body is parsed and the new
+    /// validators (if any) are stored for next time.
+    pub async fn get_or_fetch<F>(&self, client: &Client, url: &str, parse: F) -> Result<T, BotError>
+    where
+        F: FnOnce(&str) -> Result<T, BotError>,
+    {
+        let mut request = client.get(url);
+
+        {
+            let entries = self.entries.read().await;
+            if let Some(cached) = entries.get(url) {
+                if let Some(etag) = &cached.etag {
+                    request = request.header(header::IF_NONE_MATCH, etag);
+                }
+                if let Some(last_modified) = &cached.last_modified {
+                    request = request.header(header::IF_MODIFIED_SINCE, last_modified);
+                }
+            }
+        }
+
+        let response = request.send().await?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            let mut entries = self.entries.write().await;
+            if let Some(cached) = entries.get_mut(url) {
+                cached.last_used = Instant::now();
+                return Ok(cached.value.clone());
+            }
+        }
+
+        if !response.status().is_success() {
+            return Err(BotError::Http(format!(
+                "{} returned HTTP {}",
+                url,
+                response.status()
+            )));
+        }
+
+        let etag = response
+            .headers()
+            .get(header::ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let last_modified = response
+            .headers()
+            .get(header::LAST_MODIFIED)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        let body = response.text().await?;
+        let value = parse(&body)?;
+
+        self.store(url, value.clone(), etag, last_modified).await;
+
+        Ok(value)
+    }
+
+    async fn store(&self, url: &str, value: T, etag: Option<String>, last_modified: Option<String>) {
+        let mut entries = self.entries.write().await;
+
+        if !entries.contains_key(url) && entries.len() >= self.max_entries {
+            if let Some(lru_key) = entries
+                .iter()
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(key, _)| key.clone())
+            {
+                entries.remove(&lru_key);
+            }
+        }
+
+        entries.insert(
+            url.to_string(),
+            CachedEntry {
+                value,
+                etag,
+                last_modified,
+                last_used: Instant::now(),
+            },
+        );
+    }
+}
+
+impl<T: Clone + Send + Sync> Default for HttpCache<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::{Arc, Mutex};
+
+use dash_rt::runtime::Runtime;
+use dash_vm::value::Value;
+
+use crate::error::BotError;
+
+#[derive(Debug, Clone, Copy, poise::ChoiceParameter)]
+pub enum OptLevel {
+    #[name = "None"]
+    None,
+    #[name = "Basic"]
+    Basic,
+    #[name = "Aggressive"]
+    Aggressive,
+}
+
+impl OptLevel {
+    fn as_dash_level(&self) -> dash_optimizer::OptLevel {
+        match self {
+            OptLevel::None => dash_optimizer::OptLevel::None,
+            OptLevel::Basic => dash_optimizer::OptLevel::Basic,
+            OptLevel::Aggressive => dash_optimizer::OptLevel::Aggressive,
+        }
+    }
+}
+
+pub struct JsRunOutput {
+    pub console_log: String,
+    pub result: Option<String>,
+}
+
+/// Runs `source` to completion inside a fresh `dash_rt` runtime, capturing
+/// everything written to `console.log` along the way. Blocking/CPU-bound —
+/// callers are expected to run this inside `tokio::task::spawn_blocking`
+/// under a wall-clock timeout, since a runaway script has no other way to
+/// yield control back to the caller.
+pub fn run_script(source: &str, opt_level: OptLevel) -> Result<JsRunOutput, BotError> {
+    let log_buffer = Arc::new(Mutex::new(String::new()));
+
+    let mut runtime = Runtime::new();
+    runtime.set_optimization_level(opt_level.as_dash_level());
+
+    let buffer_for_console = log_buffer.clone();
+    runtime.set_console_log_hook(move |args: &[Value]| {
+        let mut buffer = buffer_for_console.lock().unwrap();
+        for (index, arg) in args.iter().enumerate() {
+            if index > 0 {
+                buffer.push(' ');
+            }
+            buffer.push_str(&arg.to_string());
+        }
+        buffer.push('\n');
+    });
+
+    let eval_result = runtime
+        .eval(source, opt_level.as_dash_level())
+        .map_err(|e| BotError::InvalidFormat(format!("JavaScript error: {}", e)));
+
+    let console_log = log_buffer.lock().unwrap().clone();
+
+    let result = eval_result?;
+    Ok(JsRunOutput {
+        console_log,
+        result: Some(result.to_string()),
+    })
+}
+
+/// Compiles `source` without executing it and renders the engine's lowered
+/// intermediate representation instead of running the program.
+pub fn emit_llir(source: &str, opt_level: OptLevel) -> Result<String, BotError> {
+    dash_compiler::FunctionCompiler::compile_str(source, opt_level.as_dash_level())
+        .map(|unit| format!("{:?}", unit.instructions))
+        .map_err(|e| BotError::InvalidFormat(format!("Compile error: {}", e)))
+}
@@ -0,0 +1,315 @@
+use aes_gcm::{
+    Aes256Gcm,
+    aead::{Aead, KeyInit, generic_array::GenericArray},
+};
+use chacha20poly1305::ChaCha20Poly1305;
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::{Sha256, Sha384, Sha512};
+
+use crate::error::BotError;
+
+pub const AEAD_KEY_LEN: usize = 32;
+pub const AEAD_NONCE_LEN: usize = 12;
+const AEAD_TAG_LEN: usize = 16;
+
+#[derive(Debug, Clone, Copy)]
+pub enum HmacAlgorithm {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl HmacAlgorithm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HmacAlgorithm::Sha256 => "HMAC-SHA256",
+            HmacAlgorithm::Sha384 => "HMAC-SHA384",
+            HmacAlgorithm::Sha512 => "HMAC-SHA512",
+        }
+    }
+
+    /// Output length of the underlying hash, in bytes. HKDF-Expand is capped
+    /// at 255 times this value per RFC 5869.
+    pub fn output_len(&self) -> usize {
+        match self {
+            HmacAlgorithm::Sha256 => 32,
+            HmacAlgorithm::Sha384 => 48,
+            HmacAlgorithm::Sha512 => 64,
+        }
+    }
+
+    pub fn tag(&self, key: &[u8], message: &[u8]) -> Vec<u8> {
+        match self {
+            HmacAlgorithm::Sha256 => hmac_sha256(key, message),
+            HmacAlgorithm::Sha384 => hmac_sha384(key, message),
+            HmacAlgorithm::Sha512 => hmac_sha512(key, message),
+        }
+    }
+}
+
+fn hmac_sha256(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn hmac_sha384(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha384>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn hmac_sha512(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// RFC 5869 HKDF-Extract: `PRK = HMAC(salt, IKM)`.
+pub fn hkdf_extract(algorithm: HmacAlgorithm, salt: &[u8], ikm: &[u8]) -> Vec<u8> {
+    algorithm.tag(salt, ikm)
+}
+
+/// RFC 5869 HKDF-Expand: `T(0) = empty`, `T(i) = HMAC(PRK, T(i-1) || info || i)`,
+/// concatenated and truncated to `length` bytes.
+pub fn hkdf_expand(
+    algorithm: HmacAlgorithm,
+    prk: &[u8],
+    info: &[u8],
+    length: usize,
+) -> Result<Vec<u8>, BotError> {
+    let max_length = 255 * algorithm.output_len();
+    if length > max_length {
+        return Err(BotError::InvalidFormat(format!(
+            "requested output length {} exceeds HKDF's maximum of {} bytes for {}",
+            length,
+            max_length,
+            algorithm.name()
+        )));
+    }
+
+    let mut okm = Vec::with_capacity(length);
+    let mut previous_block: Vec<u8> = Vec::new();
+    let mut counter: u8 = 1;
+
+    while okm.len() < length {
+        let mut input = previous_block.clone();
+        input.extend_from_slice(info);
+        input.push(counter);
+
+        let block = algorithm.tag(prk, &input);
+        okm.extend_from_slice(&block);
+        previous_block = block;
+        counter += 1;
+    }
+
+    okm.truncate(length);
+    Ok(okm)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum AeadAlgorithm {
+    Aes256Gcm,
+    ChaCha20Poly1305,
+}
+
+impl AeadAlgorithm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AeadAlgorithm::Aes256Gcm => "AES-256-GCM",
+            AeadAlgorithm::ChaCha20Poly1305 => "ChaCha20-Poly1305",
+        }
+    }
+
+    /// Encrypts `plaintext` under a fresh random nonce, returning
+    /// `nonce || ciphertext || tag`.
+    pub fn encrypt(&self, key: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, BotError> {
+        let key = validate_key(key)?;
+
+        let mut nonce_bytes = [0u8; AEAD_NONCE_LEN];
+        rand::rng().fill_bytes(&mut nonce_bytes);
+        let nonce = GenericArray::from_slice(&nonce_bytes);
+
+        let ciphertext = match self {
+            AeadAlgorithm::Aes256Gcm => {
+                let cipher = Aes256Gcm::new(GenericArray::from_slice(key));
+                cipher.encrypt(nonce, plaintext).map_err(|_| {
+                    BotError::InvalidFormat("AES-256-GCM encryption failed".to_string())
+                })?
+            }
+            AeadAlgorithm::ChaCha20Poly1305 => {
+                let cipher = ChaCha20Poly1305::new(GenericArray::from_slice(key));
+                cipher.encrypt(nonce, plaintext).map_err(|_| {
+                    BotError::InvalidFormat("ChaCha20-Poly1305 encryption failed".to_string())
+                })?
+            }
+        };
+
+        let mut sealed = nonce_bytes.to_vec();
+        sealed.extend_from_slice(&ciphertext);
+        Ok(sealed)
+    }
+
+    /// Splits `sealed` into `nonce || ciphertext || tag`, decrypts, and
+    /// rejects the input if the tag doesn't authenticate.
+    pub fn decrypt(&self, key: &[u8], sealed: &[u8]) -> Result<Vec<u8>, BotError> {
+        let key = validate_key(key)?;
+
+        if sealed.len() < AEAD_NONCE_LEN + AEAD_TAG_LEN {
+            return Err(BotError::InvalidFormat(
+                "ciphertext is too short to contain a nonce and tag".to_string(),
+            ));
+        }
+
+        let (nonce_bytes, ciphertext_and_tag) = sealed.split_at(AEAD_NONCE_LEN);
+        let nonce = GenericArray::from_slice(nonce_bytes);
+
+        let result = match self {
+            AeadAlgorithm::Aes256Gcm => {
+                let cipher = Aes256Gcm::new(GenericArray::from_slice(key));
+                cipher.decrypt(nonce, ciphertext_and_tag)
+            }
+            AeadAlgorithm::ChaCha20Poly1305 => {
+                let cipher = ChaCha20Poly1305::new(GenericArray::from_slice(key));
+                cipher.decrypt(nonce, ciphertext_and_tag)
+            }
+        };
+
+        result.map_err(|_| {
+            BotError::InvalidFormat(
+                "decryption failed: authentication tag mismatch or corrupted ciphertext"
+                    .to_string(),
+            )
+        })
+    }
+}
+
+fn validate_key(key: &[u8]) -> Result<&[u8], BotError> {
+    if key.len() != AEAD_KEY_LEN {
+        return Err(BotError::InvalidFormat(format!(
+            "key must be exactly {} bytes (got {})",
+            AEAD_KEY_LEN,
+            key.len()
+        )));
+    }
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WYCHEPROOF_JSON: &str =
+        include_str!("../../datagen/crypto/wycheproof_vectors.json");
+
+    #[derive(serde::Deserialize)]
+    struct HmacVector {
+        key: String,
+        msg: String,
+        tag: String,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct HkdfVector {
+        ikm: String,
+        salt: String,
+        info: String,
+        length: usize,
+        prk: String,
+        okm: String,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct AeadVector {
+        key: String,
+        nonce: String,
+        msg: String,
+        ct: String,
+        tag: String,
+        valid: bool,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct Vectors {
+        hmac_sha256: Vec<HmacVector>,
+        hkdf_sha256: Vec<HkdfVector>,
+        aes_256_gcm: Vec<AeadVector>,
+        chacha20_poly1305: Vec<AeadVector>,
+    }
+
+    fn load_vectors() -> Vectors {
+        serde_json::from_str(WYCHEPROOF_JSON).expect("embedded test vectors must parse")
+    }
+
+    #[test]
+    fn hmac_sha256_matches_known_vector() {
+        for vector in load_vectors().hmac_sha256 {
+            let key = hex::decode(&vector.key).unwrap();
+            let msg = hex::decode(&vector.msg).unwrap();
+            let expected = hex::decode(&vector.tag).unwrap();
+
+            let tag = HmacAlgorithm::Sha256.tag(&key, &msg);
+            assert_eq!(tag, expected);
+        }
+    }
+
+    #[test]
+    fn hkdf_sha256_matches_known_vector() {
+        for vector in load_vectors().hkdf_sha256 {
+            let ikm = hex::decode(&vector.ikm).unwrap();
+            let salt = hex::decode(&vector.salt).unwrap();
+            let info = hex::decode(&vector.info).unwrap();
+            let expected_prk = hex::decode(&vector.prk).unwrap();
+            let expected_okm = hex::decode(&vector.okm).unwrap();
+
+            let prk = hkdf_extract(HmacAlgorithm::Sha256, &salt, &ikm);
+            assert_eq!(prk, expected_prk);
+
+            let okm = hkdf_expand(HmacAlgorithm::Sha256, &prk, &info, vector.length).unwrap();
+            assert_eq!(okm, expected_okm);
+        }
+    }
+
+    #[test]
+    fn aes_256_gcm_matches_known_vectors_and_rejects_tampered_tags() {
+        check_aead_vectors(AeadAlgorithm::Aes256Gcm, &load_vectors().aes_256_gcm);
+    }
+
+    #[test]
+    fn chacha20_poly1305_matches_known_vectors_and_rejects_tampered_tags() {
+        check_aead_vectors(
+            AeadAlgorithm::ChaCha20Poly1305,
+            &load_vectors().chacha20_poly1305,
+        );
+    }
+
+    fn check_aead_vectors(algorithm: AeadAlgorithm, vectors: &[AeadVector]) {
+        for vector in vectors {
+            let key = hex::decode(&vector.key).unwrap();
+            let nonce = hex::decode(&vector.nonce).unwrap();
+            let msg = hex::decode(&vector.msg).unwrap();
+            let ct = hex::decode(&vector.ct).unwrap();
+            let tag = hex::decode(&vector.tag).unwrap();
+
+            let mut sealed = nonce.clone();
+            sealed.extend_from_slice(&ct);
+            sealed.extend_from_slice(&tag);
+
+            let decrypted = algorithm.decrypt(&key, &sealed);
+
+            if vector.valid {
+                assert_eq!(decrypted.unwrap(), msg);
+
+                // Round-trip through our own `encrypt`: it picks a random
+                // nonce, so verify by decrypting what we encrypted rather
+                // than comparing bytes directly.
+                let resealed = algorithm.encrypt(&key, &msg).unwrap();
+                let redecrypted = algorithm.decrypt(&key, &resealed).unwrap();
+                assert_eq!(redecrypted, msg);
+            } else {
+                assert!(decrypted.is_err(), "tampered tag should be rejected");
+            }
+        }
+    }
+}
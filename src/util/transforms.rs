@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+/// A single pipeline step: takes the value produced by the previous step
+/// plus the `:arg` portion of its token (if any) and produces the next
+/// value, or an error describing why that step failed.
+pub type Transform = fn(&str, Option<&str>) -> Result<String, String>;
+
+pub fn rot_char(c: char, n: u8) -> char {
+    match c {
+        'a'..='z' => ((c as u8 - b'a' + n) % 26 + b'a') as char,
+        'A'..='Z' => ((c as u8 - b'A' + n) % 26 + b'A') as char,
+        _ => c,
+    }
+}
+
+pub fn rot_string(s: &str, n: u8) -> String {
+    s.chars().map(|c| rot_char(c, n)).collect()
+}
+
+pub fn endian_swap(hex_data: &str) -> Result<String, String> {
+    let clean_hex = hex_data.replace(' ', "").replace("0x", "");
+
+    if clean_hex.len() % 2 != 0 {
+        return Err("Hex string must have even length".to_string());
+    }
+
+    let bytes = hex::decode(&clean_hex).map_err(|e| format!("Invalid hex: {}", e))?;
+    let swapped: Vec<u8> = bytes.into_iter().rev().collect();
+    Ok(hex::encode(swapped).to_uppercase())
+}
+
+pub fn base64_encode(input: &str) -> String {
+    base64::Engine::encode(&base64::engine::general_purpose::STANDARD, input)
+}
+
+pub fn base64_decode(input: &str) -> Result<String, String> {
+    let decoded = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, input)
+        .map_err(|e| format!("Invalid base64: {}", e))?;
+    String::from_utf8(decoded).map_err(|_| "Decoded data is not valid UTF-8".to_string())
+}
+
+pub fn url_encode(input: &str) -> String {
+    urlencoding::encode(input).to_string()
+}
+
+pub fn url_decode(input: &str) -> Result<String, String> {
+    urlencoding::decode(input)
+        .map(|s| s.to_string())
+        .map_err(|e| format!("Invalid URL encoding: {}", e))
+}
+
+fn transform_rot(input: &str, arg: Option<&str>) -> Result<String, String> {
+    let n: u8 = arg
+        .ok_or_else(|| "rot requires a rotation amount, e.g. rot:13".to_string())?
+        .parse()
+        .map_err(|_| "rot argument must be a number from 0-25".to_string())?;
+
+    if n > 25 {
+        return Err("rot argument must be between 0 and 25".to_string());
+    }
+
+    Ok(rot_string(input, n))
+}
+
+fn transform_endian(input: &str, _arg: Option<&str>) -> Result<String, String> {
+    endian_swap(input)
+}
+
+fn transform_base64(input: &str, arg: Option<&str>) -> Result<String, String> {
+    match arg.unwrap_or("encode") {
+        "encode" => Ok(base64_encode(input)),
+        "decode" => base64_decode(input),
+        other => Err(format!(
+            "unknown base64 mode '{}', expected 'encode' or 'decode'",
+            other
+        )),
+    }
+}
+
+fn transform_url(input: &str, arg: Option<&str>) -> Result<String, String> {
+    match arg.unwrap_or("encode") {
+        "encode" => Ok(url_encode(input)),
+        "decode" => url_decode(input),
+        other => Err(format!(
+            "unknown url mode '{}', expected 'encode' or 'decode'",
+            other
+        )),
+    }
+}
+
+/// Registry backing the `recipe` command's `op:arg | op:arg | ...`
+/// pipeline parser. Keyed by the op name a pipeline token leads with.
+pub static TRANSFORMS: Lazy<HashMap<&'static str, Transform>> = Lazy::new(|| {
+    let mut map: HashMap<&'static str, Transform> = HashMap::new();
+    map.insert("base64", transform_base64);
+    map.insert("rot", transform_rot);
+    map.insert("url", transform_url);
+    map.insert("endian", transform_endian);
+    map
+});